@@ -1,4 +1,8 @@
-use crate::{Address, BytesN, Symbol, Val, Vec};
+use std::rc::Rc;
+
+use soroban_env_common::{auth, dispatch::Invocation, AuthorizedFunction, AuthorizedInvocation};
+
+use crate::{Address, BytesN, FromValEnum, Symbol, ToValEnum, Val, Vec};
 
 #[derive(Clone)]
 //#[contracttype(crate_path = "crate", export = false)]
@@ -7,6 +11,25 @@ pub enum InvokerContractAuthEntry {
     CreateContractHostFn(CreateContractHostFnContext),
 }
 
+impl From<InvokerContractAuthEntry> for AuthorizedInvocation {
+    fn from(entry: InvokerContractAuthEntry) -> Self {
+        match entry {
+            InvokerContractAuthEntry::Contract(invocation) => AuthorizedInvocation {
+                function: (&invocation.context).into(),
+                sub_invocations: invocation
+                    .sub_invocations
+                    .into_iter()
+                    .map(|sub_invocation| (*sub_invocation).into())
+                    .collect(),
+            },
+            InvokerContractAuthEntry::CreateContractHostFn(context) => AuthorizedInvocation {
+                function: (&context).into(),
+                sub_invocations: std::vec::Vec::new(),
+            },
+        }
+    }
+}
+
 #[derive(Clone)]
 //#[contracttype(crate_path = "crate", export = false)]
 pub struct SubContractInvocation {
@@ -39,3 +62,130 @@ pub struct CreateContractHostFnContext {
 pub enum ContractExecutable {
     Wasm(BytesN<32>),
 }
+
+fn variant_val(name: &str, value: Val) -> Val {
+    Val::VecVal(Vec::new_from_slice(&[
+        Rc::new(Symbol::from(name).to_val()),
+        Rc::new(value),
+    ]))
+}
+
+fn variant(val: Val) -> Option<(Symbol, Val)> {
+    if let Val::VecVal(v) = val {
+        if v.len() == 2 {
+            let name = Symbol::from_val((*v[0]).clone())?;
+            return Some((name, (*v[1]).clone()));
+        }
+    }
+    None
+}
+
+fn field(fields: &[(Val, Val)], name: &str) -> Option<Val> {
+    let key = Symbol::from(name).to_val();
+    fields
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.clone())
+}
+
+impl From<&Context> for AuthorizedFunction {
+    fn from(context: &Context) -> Self {
+        match context {
+            Context::Contract(context) => context.into(),
+            Context::CreateContractHostFn(context) => context.into(),
+        }
+    }
+}
+
+impl From<&ContractContext> for AuthorizedFunction {
+    fn from(context: &ContractContext) -> Self {
+        AuthorizedFunction::Contract(Invocation {
+            contract: context.contract,
+            fn_name: context.fn_name,
+            args: context.args.clone(),
+        })
+    }
+}
+
+impl From<&CreateContractHostFnContext> for AuthorizedFunction {
+    fn from(context: &CreateContractHostFnContext) -> Self {
+        AuthorizedFunction::CreateContract {
+            executable: context.executable.to_val(),
+            salt: context.salt.to_val(),
+        }
+    }
+}
+
+impl ToValEnum for Context {
+    fn to_val(&self) -> Val {
+        auth::context_val(&self.into())
+    }
+}
+
+impl FromValEnum for Context {
+    fn from_val(val: Val) -> Option<Self> {
+        let (name, value) = variant(val)?;
+        match name.as_str() {
+            "Contract" => Some(Context::Contract(ContractContext::from_val(value)?)),
+            "CreateContractHostFn" => Some(Context::CreateContractHostFn(
+                CreateContractHostFnContext::from_val(value)?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl ToValEnum for ContractContext {
+    fn to_val(&self) -> Val {
+        auth::context_fields_val(&self.into())
+    }
+}
+
+impl FromValEnum for ContractContext {
+    fn from_val(val: Val) -> Option<Self> {
+        let Val::MapVal(fields) = val else {
+            return None;
+        };
+        Some(ContractContext {
+            contract: Address::from_val(field(&fields, "contract")?)?,
+            fn_name: Symbol::from_val(field(&fields, "fn_name")?)?,
+            args: Vec::from_val(field(&fields, "args")?)?,
+        })
+    }
+}
+
+impl ToValEnum for CreateContractHostFnContext {
+    fn to_val(&self) -> Val {
+        auth::context_fields_val(&self.into())
+    }
+}
+
+impl FromValEnum for CreateContractHostFnContext {
+    fn from_val(val: Val) -> Option<Self> {
+        let Val::MapVal(fields) = val else {
+            return None;
+        };
+        Some(CreateContractHostFnContext {
+            executable: ContractExecutable::from_val(field(&fields, "executable")?)?,
+            salt: BytesN::from_val(field(&fields, "salt")?)?,
+        })
+    }
+}
+
+impl ToValEnum for ContractExecutable {
+    fn to_val(&self) -> Val {
+        match self {
+            ContractExecutable::Wasm(hash) => variant_val("Wasm", hash.to_val()),
+        }
+    }
+}
+
+impl FromValEnum for ContractExecutable {
+    fn from_val(val: Val) -> Option<Self> {
+        let (name, value) = variant(val)?;
+        match name.as_str() {
+            "Wasm" => Some(ContractExecutable::Wasm(BytesN::from_val(value)?)),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{Bytes, BytesN};
-use soroban_env_common::{Address, Env, IntoVal};
+use soroban_env_common::{auth, Address, Env, IntoVal, ToValEnum};
+
+use crate::auth::ContractExecutable;
 
 pub struct Deployer {
     env: Env,
@@ -19,21 +21,23 @@ impl Deployer {
         salt: impl IntoVal<Env, BytesN<32>>,
     ) -> DeployerWithAddress {
         DeployerWithAddress {
-            _env: self.env.clone(),
+            env: self.env.clone(),
+            deployer: self.env.current_contract_address(),
             address: Address::new(&self.env),
-            _salt: salt.into_val(&self.env),
+            salt: salt.into_val(&self.env),
         }
     }
 
     pub fn with_address(
         &self,
-        _address: Address,
+        address: Address,
         salt: impl IntoVal<Env, BytesN<32>>,
     ) -> DeployerWithAddress {
         DeployerWithAddress {
-            _env: self.env.clone(),
+            env: self.env.clone(),
+            deployer: address,
             address: Address::new(&self.env),
-            _salt: salt.into_val(&self.env),
+            salt: salt.into_val(&self.env),
         }
     }
 
@@ -61,9 +65,10 @@ impl Deployer {
 }
 
 pub struct DeployerWithAddress {
-    _env: Env,
+    env: Env,
+    deployer: Address,
     address: Address,
-    _salt: BytesN<32>,
+    salt: BytesN<32>,
 }
 
 impl DeployerWithAddress {
@@ -71,7 +76,15 @@ impl DeployerWithAddress {
         self.address
     }
 
-    pub fn deploy(&self, _wasm_hash: impl IntoVal<Env, BytesN<32>>) -> Address {
+    /// Deploys the contract, which `deployer` has to authorize.
+    pub fn deploy(&self, wasm_hash: impl IntoVal<Env, BytesN<32>>) -> Address {
+        let executable = ContractExecutable::Wasm(wasm_hash.into_val(&self.env));
+        auth::require_create_contract_auth(
+            &self.env,
+            self.deployer,
+            executable.to_val(),
+            self.salt.to_val(),
+        );
         self.address
     }
 }
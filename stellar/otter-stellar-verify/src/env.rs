@@ -15,7 +15,7 @@ impl EnvTrait for Env {
     }
 
     fn ledger(&self) -> Ledger {
-        Ledger::default()
+        Ledger::new(self)
     }
 
     fn crypto(&self) -> Crypto {
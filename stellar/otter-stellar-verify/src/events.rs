@@ -1,12 +1,26 @@
-use soroban_env_common::Env;
+use soroban_env_common::{ContractEvent, Env, ToValEnum};
 
 #[derive(Clone)]
-pub struct Events();
+pub struct Events {
+    env: Env,
+}
 
 impl Events {
-    pub(crate) fn new(_env: &Env) -> Events {
-        Events()
+    pub(crate) fn new(env: &Env) -> Events {
+        Events { env: env.clone() }
+    }
+
+    pub fn publish<T, D>(&self, topics: T, data: D)
+    where
+        T: ToValEnum,
+        D: ToValEnum,
+    {
+        self.env
+            .publish_event(self.env.current_contract_address(), topics, data);
     }
 
-    pub fn publish<T, D>(&self, _topics: T, _data: D) {}
+    /// Returns every event published in this environment, oldest first.
+    pub fn all(&self) -> Vec<ContractEvent> {
+        self.env.published_events()
+    }
 }
@@ -1,14 +1,27 @@
 use crate::BytesN;
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default)]
-pub struct Ledger {}
+use soroban_env_common::{Env, LedgerInfo, Timepoint};
+
+/// The ledger of an [`Env`], returned by `env.ledger()`.
+#[derive(Clone, Debug)]
+pub struct Ledger {
+    env: Env,
+}
 
 impl Ledger {
+    pub(crate) fn new(env: &Env) -> Ledger {
+        Ledger { env: env.clone() }
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
     pub fn sequence(&self) -> u32 {
-        u32::MAX
+        self.env.ledger_info().sequence_number
     }
 
     pub fn protocol_version(&self) -> u32 {
-        u32::MAX
+        self.env.ledger_info().protocol_version
     }
 
     pub fn max_live_until_ledger(&self) -> u32 {
@@ -22,13 +35,45 @@ impl Ledger {
         }
     }
 
-    // Always return current timestamp as of the time of writing this code.
     pub fn timestamp(&self) -> u64 {
-        1701348881
+        self.env.ledger_info().timestamp
+    }
+
+    /// Returns the ledger timestamp as a [`Timepoint`].
+    pub fn timepoint(&self) -> Timepoint {
+        Timepoint::from_unix(&self.env, self.timestamp())
     }
 
-    // Always return the same value to avoid concurrency issues
     pub fn network_id(&self) -> BytesN<32> {
-        BytesN::from_array(&[1; 32])
+        BytesN::from_array(&self.env, &self.env.ledger_info().network_id)
+    }
+
+    pub fn get(&self) -> LedgerInfo {
+        self.env.ledger_info()
+    }
+
+    pub fn set(&self, info: LedgerInfo) {
+        self.env.set_ledger_info(info);
+    }
+
+    pub fn set_sequence_number(&self, sequence_number: u32) {
+        self.with_mut(|info| info.sequence_number = sequence_number);
+    }
+
+    pub fn set_timestamp(&self, timestamp: u64) {
+        self.with_mut(|info| info.timestamp = timestamp);
+    }
+
+    pub fn set_protocol_version(&self, protocol_version: u32) {
+        self.with_mut(|info| info.protocol_version = protocol_version);
+    }
+
+    pub fn with_mut<F>(&self, f: F)
+    where
+        F: FnOnce(&mut LedgerInfo),
+    {
+        let mut info = self.get();
+        f(&mut info);
+        self.set(info);
     }
 }
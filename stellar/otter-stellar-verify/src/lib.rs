@@ -1,5 +1,4 @@
 pub mod auth;
-pub mod bytes;
 pub mod crypto;
 pub mod deploy;
 pub mod env;
@@ -14,21 +13,24 @@ pub mod xdr;
 
 pub use {
     auth::*,
-    bytes::{Bytes, BytesN},
     crypto::Crypto,
     deploy::Deployer,
     env::EnvTrait,
     events::Events,
     map::Map,
     prng::Prng,
+    soroban_env_common::vec,
     soroban_env_common::{
         address::Address,
-        env::{Env, IntoVal, TryFromVal, TryIntoVal},
+        bytes::{self, Bytes, BytesN},
+        dispatch,
+        env::{ContractEvent, Env, IntoVal, LedgerInfo, TryFromVal, TryIntoVal},
         symbol::Symbol,
         symbol_short,
-        token::{self, AdminClient, MockToken, TokenClient, TokenInterface},
-        BytesObject, ConversionError, EnumType, FromValEnum, String, Timepoint, ToValEnum, Val,
-        Vec,
+        token::{self, AdminClient, MockToken, StellarAssetClient, TokenClient, TokenInterface},
+        AuthRecord, AuthorizationEntry, AuthorizedFunction, AuthorizedInvocation, BytesObject,
+        ContractAuthorization, ConversionError, Duration, FromValEnum, String, Timepoint,
+        ToValEnum, Val, Vec, I256, U256,
     },
     stellar_sdk_macros::{
         contract, contractclient, contracterror, contractimpl, contractimport, contractmeta,
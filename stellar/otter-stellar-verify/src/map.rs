@@ -1,23 +1,20 @@
-use soroban_env_common::{Env, FromValEnum, ToValEnum, Val};
-
-const CAPACITY: usize = 10;
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Map<K, V>
-where
-    K: PartialEq + Eq + PartialOrd + Ord,
-    V: PartialEq + Eq + PartialOrd + Ord,
-{
-    buckets: Vec<(K, V)>,
+use soroban_env_common::{vec, ConversionError, Env, FromValEnum, ToValEnum, Val, Vec};
+
+/// A map with unique keys, kept sorted by key like host maps are.
+///
+/// Like a `Vec`, a map holds at most [`vec::capacity`] entries.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Map<K, V> {
+    buckets: std::vec::Vec<(K, V)>,
 }
 
 impl<K, V> ToValEnum for Map<K, V>
 where
-    K: ToValEnum + PartialEq + Eq + PartialOrd + Ord,
-    V: ToValEnum + PartialEq + Eq + PartialOrd + Ord,
+    K: ToValEnum,
+    V: ToValEnum,
 {
     fn to_val(&self) -> Val {
-        let map_val: Vec<(Val, Val)> = self
+        let map_val: std::vec::Vec<(Val, Val)> = self
             .buckets
             .iter()
             .map(|(k, v)| (k.to_val(), v.to_val()))
@@ -29,20 +26,20 @@ where
 
 impl<K, V> FromValEnum for Map<K, V>
 where
-    K: FromValEnum + PartialEq + Eq + PartialOrd + Ord,
-    V: FromValEnum + PartialEq + Eq + PartialOrd + Ord,
+    K: FromValEnum + Clone + Ord,
+    V: FromValEnum + Clone,
 {
     fn from_val(val: Val) -> Option<Self> {
         if let Val::MapVal(vec_val) = val {
-            let mut buckets = Vec::new();
+            let mut map = Map::default();
 
             for (k_val, v_val) in vec_val {
                 let k = K::from_val(k_val)?;
                 let v = V::from_val(v_val)?;
-                buckets.push((k, v));
+                map.set(k, v);
             }
 
-            Some(Map { buckets })
+            Some(map)
         } else {
             None
         }
@@ -51,12 +48,13 @@ where
 
 impl<K, V> Map<K, V>
 where
-    K: Copy + Default + PartialEq + Eq + PartialOrd + Ord,
-    V: Copy + Default + PartialEq + Eq + PartialOrd + Ord,
+    K: Clone + Ord,
+    V: Clone,
 {
     pub fn new(_env: &Env) -> Self {
-        let buckets = Vec::with_capacity(CAPACITY);
-        Map { buckets }
+        Map {
+            buckets: std::vec::Vec::new(),
+        }
     }
 
     pub fn env(&self) -> Env {
@@ -71,51 +69,88 @@ where
         map
     }
 
-    pub fn set(&mut self, key: K, value: V) {
-        self.buckets.push((key, value));
+    fn position(&self, key: &K) -> Result<usize, usize> {
+        self.buckets.binary_search_by(|(k, _)| k.cmp(key))
     }
 
-    pub fn contains_key(&self, key: K) -> bool {
-        for (k, _) in &self.buckets {
-            if *k == key {
-                return true;
+    /// Sets the value of `key`, replacing any previous value.
+    pub fn set(&mut self, key: K, value: V) {
+        match self.position(&key) {
+            Ok(index) => self.buckets[index].1 = value,
+            Err(index) => {
+                let capacity = vec::capacity();
+                if self.buckets.len() >= capacity {
+                    panic!("Map capacity exceeded: at most {} entries", capacity);
+                }
+                self.buckets.insert(index, (key, value))
             }
         }
-        false
+    }
+
+    pub fn contains_key(&self, key: K) -> bool {
+        self.position(&key).is_ok()
     }
 
     pub fn insert(&mut self, key: K, value: V) {
-        self.buckets.push((key, value));
+        self.set(key, value);
     }
 
     pub fn get(&self, key: K) -> Option<V> {
-        for (k, v) in &self.buckets {
-            if *k == key {
-                return Some(*v);
-            }
-        }
-        None
+        let index = self.position(&key).ok()?;
+        Some(self.buckets[index].1.clone())
     }
 
-    pub fn remove(&mut self, key: K) -> Option<()> {
-        if let Some(index) = self.buckets.iter().position(|(k, _)| *k == key) {
-            let (_, _) = self.buckets.remove(index);
-            Some(())
-        } else {
-            None
-        }
+    /// Returns the value of `key`, or `None` if the map doesn't contain it.
+    ///
+    /// Values always convert, as they are stored with their type.
+    pub fn try_get(&self, key: K) -> Result<Option<V>, ConversionError> {
+        Ok(self.get(key))
+    }
+
+    /// Returns the value of `key`.
+    ///
+    /// ### Panics
+    ///
+    /// If the map doesn't contain `key`.
+    #[track_caller]
+    pub fn get_unchecked(&self, key: K) -> V {
+        self.get(key).expect("key not found")
+    }
+
+    /// Removes `key`, returning its value if the map contained it.
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let index = self.position(&key).ok()?;
+        let (_, value) = self.buckets.remove(index);
+        Some(value)
+    }
+
+    /// Removes `key`.
+    ///
+    /// ### Panics
+    ///
+    /// If the map doesn't contain `key`.
+    #[track_caller]
+    pub fn remove_unchecked(&mut self, key: K) {
+        self.remove(key).expect("key not found");
     }
 
+    /// Returns the keys, in order.
     pub fn keys(&self) -> Vec<K> {
-        self.buckets.iter().map(|(k, _)| *k).collect()
+        self.buckets.iter().map(|(k, _)| k.clone()).collect()
     }
 
+    /// Returns the values, in key order.
     pub fn values(&self) -> Vec<V> {
-        self.buckets.iter().map(|(_, v)| *v).collect()
+        self.buckets.iter().map(|(_, v)| v.clone()).collect()
+    }
+
+    /// Returns an iterator over clones of the entries, in key order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, V)> + '_ {
+        self.buckets.iter().cloned()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.buckets.len() == 0
+        self.buckets.is_empty()
     }
 
     pub fn len(&self) -> u32 {
@@ -125,23 +160,34 @@ where
 
 impl<K, V> Default for Map<K, V>
 where
-    K: Copy + Default + PartialEq + Eq + PartialOrd + Ord,
-    V: Copy + Default + PartialEq + Eq + PartialOrd + Ord,
+    K: Clone + Ord,
+    V: Clone,
 {
     fn default() -> Self {
         Self::new(&Env::default())
     }
 }
 
+impl<K, V> IntoIterator for Map<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buckets.into_iter()
+    }
+}
+
 #[cfg(any(kani, feature = "kani"))]
 impl<K, V> kani::Arbitrary for Map<K, V>
 where
-    K: kani::Arbitrary + Default + PartialEq + Eq + PartialOrd + Ord + Clone + Copy,
-    V: kani::Arbitrary + Default + PartialEq + Eq + PartialOrd + Ord + Clone + Copy,
+    K: kani::Arbitrary + Clone + Ord,
+    V: kani::Arbitrary + Clone,
 {
     fn any() -> Self {
         let mut map = Map::<K, V>::new(&Env::default());
-        for _ in 0..kani::any::<u8>() % (CAPACITY as u8) {
+        let len: usize = kani::any();
+        kani::assume(len <= vec::capacity());
+        for _ in 0..len {
             map.set(kani::any(), kani::any());
         }
         map
@@ -6,7 +6,7 @@ trait SymbolExt {
 
 impl SymbolExt for Symbol {
     fn new(_env: Env, sym: Symbol) -> Self {
-        let mut bytes = [0; 10];
+        let mut bytes = [0; 32];
         let sym_bytes = sym.as_bytes();
         let mut n = 0;
         while n < sym_bytes.len() {
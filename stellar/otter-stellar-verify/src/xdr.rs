@@ -1,10 +1,11 @@
 use crate::Bytes;
-#[cfg(any(kani, feature = "kani"))]
-use soroban_env_common::ToValEnum;
-use soroban_env_common::{ConversionError, Env};
-
-#[cfg(any(kani, feature = "kani"))]
-use soroban_env_common::FromValEnum;
+use soroban_env_common::{ConversionError, Env, FromValEnum, ToValEnum};
+#[cfg(not(any(kani, feature = "kani")))]
+use {
+    crate::Vec,
+    soroban_env_common::{from_scval, to_scval},
+    stellar_xdr::curr::{Limits, ReadXdr, ScVal, WriteXdr},
+};
 
 pub trait ToXdr {
     fn to_xdr(self, _env: &Env) -> Bytes;
@@ -34,3 +35,40 @@ where
         Ok(kani::any::<T>())
     }
 }
+
+/// Encodes the value as an XDR `ScVal`, byte for byte as the host does.
+///
+/// Encodings easily outgrow the `Vec` capacity (an `Address` alone takes
+/// 40 bytes), so the returned `Bytes` isn't bounded by it. Addresses that
+/// `env` decoded from XDR encode as the network address they came from.
+///
+/// ### Panics
+///
+/// If the value has no `ScVal` representation.
+#[cfg(not(any(kani, feature = "kani")))]
+impl<T> ToXdr for T
+where
+    T: ToValEnum,
+{
+    fn to_xdr(self, env: &Env) -> Bytes {
+        let xdr = to_scval(env, &self.to_val())
+            .and_then(|scval| scval.to_xdr(Limits::none()).map_err(|_| ConversionError))
+            .expect("value can't be encoded as XDR");
+        Bytes(Vec::from_slice_unbounded(&xdr))
+    }
+}
+
+/// Decodes a value from an XDR `ScVal`.
+///
+/// Network addresses decode to `Address`es that `env` encodes back to the
+/// same `ScAddress`.
+#[cfg(not(any(kani, feature = "kani")))]
+impl<T> FromXdr for T
+where
+    T: FromValEnum,
+{
+    fn from_xdr(env: &Env, b: &Bytes) -> Result<Self, ConversionError> {
+        let scval = ScVal::from_xdr(b.as_slice(), Limits::none()).map_err(|_| ConversionError)?;
+        T::from_val(from_scval(env, &scval)?).ok_or(ConversionError)
+    }
+}
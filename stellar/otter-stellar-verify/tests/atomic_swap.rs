@@ -78,10 +78,13 @@ mod test {
 
     use super::*;
     use otter_stellar_verify::{token, Address, Env};
-    use token::AdminClient as TokenAdminClient;
+    use token::StellarAssetClient as TokenAdminClient;
     use token::TokenClient;
 
-    fn create_token_contract(e: &Env, admin: Address) -> (TokenClient, TokenAdminClient) {
+    fn create_token_contract<'a>(
+        e: &Env,
+        admin: Address,
+    ) -> (TokenClient<'a>, TokenAdminClient<'a>) {
         let contract_address = e.register_stellar_asset_contract(admin);
         (
             TokenClient::new(e, &contract_address),
@@ -105,17 +108,16 @@ mod test {
         token_a_admin.mint(&a, &1000);
         token_b_admin.mint(&b, &5000);
 
-        let _ = env.register_contract(None, AtomicSwapContract);
-        AtomicSwapContract::swap(
-            env.clone(),
-            a,
-            b,
-            token_a.address,
-            token_b.address,
-            1000,
-            4500,
-            5000,
-            950,
+        let contract_id = env.register_contract(None, AtomicSwapContract);
+        AtomicSwapContractClient::new(&env, &contract_id).swap(
+            &a,
+            &b,
+            &token_a.address,
+            &token_b.address,
+            &1000,
+            &4500,
+            &5000,
+            &950,
         );
 
         assert_eq!(token_a.balance(&a), 50);
@@ -123,6 +125,13 @@ mod test {
 
         assert_eq!(token_b.balance(&a), 4500);
         assert_eq!(token_b.balance(&b), 500);
+
+        let auths = env.auths();
+        let a_auth = auths.iter().find(|auth| auth.address == a).unwrap();
+        assert_eq!(
+            a_auth.args,
+            (token_a.address, token_b.address, 1000i128, 4500i128).into_val(&env)
+        );
     }
 }
 
@@ -131,10 +140,13 @@ mod verification {
 
     use super::*;
     use otter_stellar_verify::{Address, Env};
-    use token::AdminClient as TokenAdminClient;
+    use token::StellarAssetClient as TokenAdminClient;
     use token::TokenClient;
 
-    fn create_token_contract<'a>(e: &Env, admin: &Address) -> (TokenClient, TokenAdminClient) {
+    fn create_token_contract<'a>(
+        e: &Env,
+        admin: &Address,
+    ) -> (TokenClient<'a>, TokenAdminClient<'a>) {
         let contract_address = e.register_stellar_asset_contract(admin.clone());
         (
             TokenClient::new(e, &contract_address),
@@ -168,18 +180,17 @@ mod verification {
         kani::assume(amount_b > min_b_for_a);
         kani::assume(amount_a > min_a_for_b);
 
-        let _ = env.register_contract(None, AtomicSwapContract);
+        let contract_id = env.register_contract(None, AtomicSwapContract);
         // Call the contract.
-        AtomicSwapContract::swap(
-            env.clone(),
-            a.clone(),
-            b.clone(),
-            token_a.address.clone(),
-            token_b.address.clone(),
-            amount_a,
-            min_b_for_a,
-            amount_b,
-            min_a_for_b,
+        AtomicSwapContractClient::new(&env, &contract_id).swap(
+            &a,
+            &b,
+            &token_a.address,
+            &token_b.address,
+            &amount_a,
+            &min_b_for_a,
+            &amount_b,
+            &min_a_for_b,
         );
 
         // Verify the postconditions.
@@ -0,0 +1,81 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+use counter::{Counter, CounterClient};
+use plain::{Plain, PlainClient};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const COUNT: Symbol = symbol_short!("COUNT");
+
+mod counter {
+    use super::*;
+
+    #[contract]
+    pub struct Counter;
+
+    #[contractimpl]
+    impl Counter {
+        pub fn __constructor(env: Env, admin: Address, count: u32) {
+            env.storage().instance().set(&ADMIN, &admin);
+            env.storage().instance().set(&COUNT, &count);
+        }
+
+        pub fn admin(env: Env) -> Address {
+            env.storage().instance().get(&ADMIN).unwrap()
+        }
+
+        pub fn count(env: Env) -> u32 {
+            env.storage().instance().get(&COUNT).unwrap()
+        }
+    }
+}
+
+mod plain {
+    use super::*;
+
+    #[contract]
+    pub struct Plain;
+
+    #[contractimpl]
+    impl Plain {
+        pub fn count(env: Env) -> u32 {
+            env.storage().instance().get(&COUNT).unwrap_or(0)
+        }
+    }
+}
+
+#[test]
+fn test_register_runs_constructor() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+
+    let counter = CounterClient::new(&env, &env.register(Counter, (admin, 5u32)));
+    assert_eq!(counter.admin(), admin);
+    assert_eq!(counter.count(), 5);
+    assert_eq!(env.current_contract_address(), counter.address);
+    assert!(env.call_stack().is_empty());
+}
+
+#[test]
+fn test_register_without_constructor() {
+    let env = Env::default();
+
+    let plain = PlainClient::new(&env, &env.register(Plain, ()));
+    assert_eq!(plain.count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "constructor arguments given to a contract without __constructor")]
+fn test_register_args_without_constructor() {
+    let env = Env::default();
+
+    env.register(Plain, (5u32,));
+}
+
+#[test]
+#[should_panic(expected = "expects 2 arguments, got 1")]
+fn test_register_wrong_constructor_args() {
+    let env = Env::default();
+
+    env.register(Counter, (5u32,));
+}
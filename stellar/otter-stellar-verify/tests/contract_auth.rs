@@ -0,0 +1,251 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{
+    auth::{
+        ContractContext, ContractExecutable, CreateContractHostFnContext, InvokerContractAuthEntry,
+        SubContractInvocation,
+    },
+    contract, contractimpl,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, IntoVal, Symbol,
+};
+
+use inner::{Inner, InnerClient};
+use middle::{Middle, MiddleClient};
+use outer::{Outer, OuterClient};
+
+mod inner {
+    use super::*;
+
+    #[contract]
+    pub struct Inner;
+
+    #[contractimpl]
+    impl Inner {
+        pub fn guarded(_env: Env, owner: Address, amount: u32) -> u32 {
+            owner.require_auth();
+            amount
+        }
+    }
+}
+
+mod middle {
+    use super::*;
+
+    #[contract]
+    pub struct Middle;
+
+    #[contractimpl]
+    impl Middle {
+        pub fn relay(env: Env, inner: Address, owner: Address, amount: u32) -> u32 {
+            InnerClient::new(&env, &inner).guarded(&owner, &amount)
+        }
+
+        pub fn relay_transfer(env: Env, token: Address, from: Address, to: Address, amount: i128) {
+            TokenClient::new(&env, &token).transfer(&from, &to, &amount);
+        }
+
+        pub fn deploy(env: Env, deployer: Address, wasm_hash: BytesN<32>) -> Address {
+            env.deployer()
+                .with_address(deployer, BytesN::from_array(&env, &[7; 32]))
+                .deploy(wasm_hash)
+        }
+    }
+}
+
+mod outer {
+    use super::*;
+    use soroban_sdk::vec;
+
+    #[contract]
+    pub struct Outer;
+
+    #[contractimpl]
+    impl Outer {
+        /// Calls `inner.guarded` through `middle`, authorizing `amounts`
+        /// for it first.
+        pub fn run(
+            env: Env,
+            middle: Address,
+            inner: Address,
+            amount: u32,
+            authorized_amounts: soroban_sdk::Vec<u32>,
+        ) -> u32 {
+            let owner = env.current_contract_address();
+            let mut auth_entries = vec![&env];
+            for authorized_amount in authorized_amounts.iter() {
+                auth_entries.push(InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: inner,
+                        fn_name: Symbol::new(&env, "guarded"),
+                        args: (owner, authorized_amount).into_val(&env),
+                    },
+                    sub_invocations: vec![&env],
+                }));
+            }
+            env.authorize_as_current_contract(auth_entries);
+            MiddleClient::new(&env, &middle).relay(&inner, &owner, &amount)
+        }
+
+        /// Deploys `wasm_hash` from the current contract through `middle`.
+        pub fn run_deploy(env: Env, middle: Address, wasm_hash: BytesN<32>, authorize: bool) {
+            if authorize {
+                env.authorize_as_current_contract(vec![
+                    &env,
+                    InvokerContractAuthEntry::CreateContractHostFn(CreateContractHostFnContext {
+                        executable: ContractExecutable::Wasm(wasm_hash.clone()),
+                        salt: BytesN::from_array(&env, &[7; 32]),
+                    }),
+                ]);
+            }
+            MiddleClient::new(&env, &middle).deploy(&env.current_contract_address(), &wasm_hash);
+        }
+
+        /// Pays `amount` of `token` from the current contract through
+        /// `middle`, authorizing the transfer first if `authorize` is set.
+        pub fn pay(
+            env: Env,
+            middle: Address,
+            token: Address,
+            to: Address,
+            amount: i128,
+            authorize: bool,
+        ) {
+            let owner = env.current_contract_address();
+            if authorize {
+                env.authorize_as_current_contract(vec![
+                    &env,
+                    InvokerContractAuthEntry::Contract(SubContractInvocation {
+                        context: ContractContext {
+                            contract: token,
+                            fn_name: Symbol::new(&env, "transfer"),
+                            args: (owner, to, amount).into_val(&env),
+                        },
+                        sub_invocations: vec![&env],
+                    }),
+                ]);
+            }
+            MiddleClient::new(&env, &middle).relay_transfer(&token, &owner, &to, &amount);
+        }
+
+        pub fn run_direct(env: Env, inner: Address, amount: u32) -> u32 {
+            InnerClient::new(&env, &inner).guarded(&env.current_contract_address(), &amount)
+        }
+    }
+}
+
+#[test]
+fn test_direct_call_is_authorized() {
+    let env = Env::default();
+    let inner = env.register_contract(None, Inner);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    assert_eq!(outer.run_direct(&inner, &7), 7);
+}
+
+#[test]
+fn test_authorize_as_current_contract() {
+    let env = Env::default();
+    let inner = env.register_contract(None, Inner);
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    assert_eq!(outer.run(&middle, &inner, &7, &vec![&env, 7]), 7);
+
+    let authorizations = env.contract_authorizations();
+    assert_eq!(authorizations.len(), 1);
+    assert_eq!(authorizations[0].contract, outer.address);
+    assert_eq!(authorizations[0].used.len(), 1);
+    assert!(authorizations[0].unused().is_empty());
+    assert!(!authorizations[0].active);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_missing_contract_authorization() {
+    let env = Env::default();
+    let inner = env.register_contract(None, Inner);
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    outer.run(&middle, &inner, &7, &vec![&env]);
+}
+
+fn setup_token(env: &Env, holder: &Address) -> TokenClient<'static> {
+    let admin = Address::new(env);
+    let token = env.register_stellar_asset_contract(admin);
+    StellarAssetClient::new(env, &token).mint(holder, &1000);
+    TokenClient::new(env, &token)
+}
+
+#[test]
+fn test_contract_authorizes_token_transfer() {
+    let env = Env::default();
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+    let token = setup_token(&env, &outer.address);
+    let to = Address::new(&env);
+
+    outer.pay(&middle, &token.address, &to, &100, &true);
+    assert_eq!(token.balance(&outer.address), 900);
+    assert_eq!(token.balance(&to), 100);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_token_transfer_needs_contract_authorization() {
+    let env = Env::default();
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+    let token = setup_token(&env, &outer.address);
+    let to = Address::new(&env);
+
+    outer.pay(&middle, &token.address, &to, &100, &false);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_contract_authorization_for_other_args() {
+    let env = Env::default();
+    let inner = env.register_contract(None, Inner);
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    outer.run(&middle, &inner, &7, &vec![&env, 8]);
+}
+
+#[test]
+fn test_over_broad_contract_authorization() {
+    let env = Env::default();
+    let inner = env.register_contract(None, Inner);
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    outer.run(&middle, &inner, &7, &vec![&env, 7, 8]);
+
+    let unused: Vec<_> = env
+        .contract_authorizations()
+        .iter()
+        .flat_map(|authorization| authorization.unused())
+        .collect();
+    assert_eq!(unused.len(), 1);
+}
+
+#[test]
+fn test_authorize_create_contract() {
+    let env = Env::default();
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    outer.run_deploy(&middle, &BytesN::from_array(&env, &[1; 32]), &true);
+    assert!(env.contract_authorizations()[0].unused().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_missing_create_contract_authorization() {
+    let env = Env::default();
+    let middle = env.register_contract(None, Middle);
+    let outer = OuterClient::new(&env, &env.register_contract(None, Outer));
+
+    outer.run_deploy(&middle, &BytesN::from_array(&env, &[1; 32]), &false);
+}
@@ -0,0 +1,286 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{
+    auth::Context, contract, contractimpl, dispatch::Invocation, symbol_short, Address,
+    AuthorizationEntry, AuthorizedFunction, AuthorizedInvocation, BytesN, Env, IntoVal, LedgerInfo,
+    Symbol, ToValEnum, Vec,
+};
+
+use account::{PinAccount, PinAccountClient};
+use batch::{Batch, BatchClient};
+use counter::{Counter, CounterClient};
+
+const OWNER: Symbol = symbol_short!("OWNER");
+const CHECKED: Symbol = symbol_short!("CHECKED");
+const COUNT: Symbol = symbol_short!("COUNT");
+
+mod account {
+    use super::*;
+
+    #[contract]
+    pub struct PinAccount;
+
+    #[contractimpl]
+    impl PinAccount {
+        pub fn init(env: Env, pin: u32) {
+            env.storage().instance().set(&OWNER, &pin);
+        }
+
+        #[allow(non_snake_case)]
+        pub fn __check_auth(
+            env: Env,
+            _signature_payload: BytesN<32>,
+            pin: u32,
+            auth_contexts: Vec<Context>,
+        ) {
+            let owner: u32 = env.storage().instance().get(&OWNER).unwrap();
+            if pin != owner {
+                panic!("wrong pin");
+            }
+            for context in auth_contexts.iter() {
+                if let Context::Contract(context) = context {
+                    env.storage().instance().set(&CHECKED, &context.fn_name);
+                }
+            }
+        }
+
+        /// Returns the function name of the last context checked.
+        pub fn checked(env: Env) -> Option<Symbol> {
+            env.storage().instance().get(&CHECKED)
+        }
+    }
+}
+
+mod counter {
+    use super::*;
+
+    #[contract]
+    pub struct Counter;
+
+    #[contractimpl]
+    impl Counter {
+        pub fn increment(env: Env, user: Address, amount: u32) -> u32 {
+            user.require_auth();
+            let count: u32 = env.storage().instance().get(&COUNT).unwrap_or(0) + amount;
+            env.storage().instance().set(&COUNT, &count);
+            count
+        }
+    }
+}
+
+mod batch {
+    use super::*;
+
+    #[contract]
+    pub struct Batch;
+
+    #[contractimpl]
+    impl Batch {
+        /// Increments `counter` twice on behalf of `user`.
+        pub fn twice(env: Env, user: Address, counter: Address) -> u32 {
+            user.require_auth();
+            let counter = CounterClient::new(&env, &counter);
+            counter.increment(&user, &1);
+            counter.increment(&user, &1)
+        }
+    }
+}
+
+fn increment_entry(
+    env: &Env,
+    account: Address,
+    counter: Address,
+    amount: u32,
+    nonce: i64,
+    pin: u32,
+) -> AuthorizationEntry {
+    AuthorizationEntry {
+        address: account,
+        nonce,
+        signature_expiration_ledger: 100,
+        root_invocation: AuthorizedInvocation {
+            function: AuthorizedFunction::Contract(Invocation {
+                contract: counter,
+                fn_name: Symbol::from("increment"),
+                args: (account, amount).into_val(env),
+            }),
+            sub_invocations: std::vec::Vec::new(),
+        },
+        signature: pin.to_val(),
+    }
+}
+
+#[test]
+fn test_check_auth() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1234)]);
+    assert_eq!(counter.increment(&account, &5), 5);
+    let auths = env.auths();
+    assert_eq!(auths.last().unwrap().contract, counter.address);
+    assert_eq!(auths.last().unwrap().args.len(), 2);
+
+    let checked = PinAccountClient::new(&env, &account).checked();
+    assert_eq!(checked, Some(Symbol::from("increment")));
+}
+
+#[test]
+#[should_panic(expected = "wrong pin")]
+fn test_check_auth_rejects() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1111)]);
+    counter.increment(&account, &5);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_check_auth_missing_signature() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    counter.increment(&account, &5);
+}
+
+#[test]
+fn test_mock_all_auths_skips_check_auth() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.mock_all_auths();
+    assert_eq!(counter.increment(&account, &5), 5);
+    assert_eq!(PinAccountClient::new(&env, &account).checked(), None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_check_auth_other_args() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1234)]);
+    counter.increment(&account, &6);
+}
+
+#[test]
+#[should_panic(expected = "nonce already used")]
+fn test_check_auth_replay() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1234)]);
+    counter.increment(&account, &5);
+    counter.increment(&account, &5);
+}
+
+#[test]
+#[should_panic(expected = "nonce already used")]
+fn test_check_auth_nonce_reuse() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1234)]);
+    counter.increment(&account, &5);
+    env.set_auths(&[increment_entry(&env, account, counter.address, 6, 0, 1234)]);
+    counter.increment(&account, &6);
+}
+
+/// An entry authorizing `batch.twice`, and the two increments it makes.
+fn twice_entry(
+    env: &Env,
+    account: Address,
+    batch: Address,
+    counter: Address,
+) -> AuthorizationEntry {
+    let increment = increment_entry(env, account, counter, 1, 0, 1234).root_invocation;
+    AuthorizationEntry {
+        address: account,
+        nonce: 0,
+        signature_expiration_ledger: 100,
+        root_invocation: AuthorizedInvocation {
+            function: AuthorizedFunction::Contract(Invocation {
+                contract: batch,
+                fn_name: Symbol::from("twice"),
+                args: (account, counter).into_val(env),
+            }),
+            sub_invocations: std::vec![increment.clone(), increment],
+        },
+        signature: 1234u32.to_val(),
+    }
+}
+
+#[test]
+fn test_check_auth_sub_invocations() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+    let batch = BatchClient::new(&env, &env.register_contract(None, Batch));
+
+    env.set_auths(&[twice_entry(&env, account, batch.address, counter.address)]);
+    assert_eq!(batch.twice(&account, &counter.address), 2);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_check_auth_sub_invocation_without_root() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+    let batch = env.register_contract(None, Batch);
+
+    env.set_auths(&[twice_entry(&env, account, batch, counter.address)]);
+    counter.increment(&account, &1);
+}
+
+#[test]
+#[should_panic(expected = "signature has expired")]
+fn test_check_auth_expired() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    env.set_auths(&[increment_entry(&env, account, counter.address, 5, 0, 1234)]);
+    env.set_ledger_info(LedgerInfo {
+        sequence_number: 101,
+        ..env.ledger_info()
+    });
+    counter.increment(&account, &5);
+}
+
+#[test]
+fn test_signature_payload() {
+    let env = Env::default();
+    let account = env.register_contract(None, PinAccount);
+    PinAccountClient::new(&env, &account).init(&1234);
+    let counter = CounterClient::new(&env, &env.register_contract(None, Counter));
+
+    let entry = increment_entry(&env, account, counter.address, 5, 0, 1234);
+    let payload = entry.signature_payload(&env);
+    assert_eq!(payload, entry.signature_payload(&env));
+    assert_ne!(
+        payload,
+        increment_entry(&env, account, counter.address, 5, 1, 1234).signature_payload(&env)
+    );
+    assert_ne!(
+        payload,
+        increment_entry(&env, account, counter.address, 6, 0, 1234).signature_payload(&env)
+    );
+}
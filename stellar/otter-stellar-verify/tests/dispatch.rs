@@ -0,0 +1,57 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, Env, Symbol};
+
+pub trait Named {
+    fn name(env: Env) -> Symbol;
+}
+
+#[contract]
+pub struct Multi;
+
+#[contractimpl]
+impl Multi {
+    pub fn first(_env: Env) -> u32 {
+        1
+    }
+}
+
+#[contractimpl]
+impl Named for Multi {
+    fn name(_env: Env) -> Symbol {
+        Symbol::from("multi")
+    }
+}
+
+#[contractimpl]
+impl Multi {
+    pub fn second(_env: Env) -> u32 {
+        2
+    }
+}
+
+#[contractimpl(slot = 40)]
+impl Multi {
+    pub fn third(_env: Env) -> u32 {
+        3
+    }
+}
+
+#[test]
+fn test_impl_blocks_dispatch() {
+    let env = Env::default();
+    let id = env.register_contract(None, Multi);
+    let client = MultiClient::new(&env, &id);
+
+    assert_eq!(client.first(), 1);
+    assert_eq!(client.second(), 2);
+    assert_eq!(client.third(), 3);
+    assert_eq!(client.name(), Symbol::from("multi"));
+    assert_eq!(
+        env.call_contract(&id, &Symbol::from("second"), &[]),
+        Some(soroban_sdk::Val::U32Val(2))
+    );
+    assert_eq!(
+        env.call_contract(&id, &Symbol::from("name"), &[]),
+        Some(soroban_sdk::Val::SymbolVal(Symbol::from("multi")))
+    );
+}
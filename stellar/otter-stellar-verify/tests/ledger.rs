@@ -0,0 +1,37 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, symbol_short, Duration, Env, Symbol, Timepoint};
+
+const UNLOCK: Symbol = symbol_short!("UNLOCK");
+
+#[contract]
+pub struct TimeLock;
+
+#[contractimpl]
+impl TimeLock {
+    pub fn lock(env: Env, duration: Duration) {
+        let unlock = env
+            .ledger()
+            .timepoint()
+            .checked_add(&duration)
+            .expect("unlock time overflows");
+        env.storage().instance().set(&UNLOCK, &unlock);
+    }
+
+    pub fn is_unlocked(env: Env) -> bool {
+        let unlock: Timepoint = env.storage().instance().get(&UNLOCK).unwrap();
+        env.ledger().timepoint() >= unlock
+    }
+}
+
+#[test]
+fn test_timepoint_from_ledger() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(env.ledger().timepoint(), Timepoint::from_unix(&env, 1_000));
+
+    let client = TimeLockClient::new(&env, &env.register_contract(None, TimeLock));
+    client.lock(&Duration::from_seconds(&env, 60));
+    assert!(!client.is_unlocked());
+    env.ledger().set_timestamp(1_060);
+    assert!(client.is_unlocked());
+}
@@ -0,0 +1,68 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{Address, Env, FromValEnum, Map, ToValEnum, Vec};
+
+#[test]
+fn test_map_unique_sorted_keys() {
+    let env = Env::default();
+    let mut map = Map::from_array(&env, [(3u32, 30u32), (1, 10), (2, 20)]);
+    map.set(1, 11);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(1), Some(11));
+    assert_eq!(map.keys(), Vec::from([1, 2, 3]));
+    assert_eq!(map.values(), Vec::from([11, 20, 30]));
+    assert_eq!(
+        map.iter().collect::<std::vec::Vec<_>>(),
+        [(1, 11), (2, 20), (3, 30)]
+    );
+}
+
+#[test]
+fn test_map_remove() {
+    let env = Env::default();
+    let mut map = Map::from_array(&env, [(1u32, 10u32), (2, 20)]);
+    assert_eq!(map.remove(1), Some(10));
+    assert_eq!(map.remove(1), None);
+    assert!(!map.contains_key(1));
+    assert_eq!(map.try_get(2), Ok(Some(20)));
+    assert_eq!(map.try_get(1), Ok(None));
+    assert_eq!(map.get_unchecked(2), 20);
+}
+
+#[test]
+#[should_panic(expected = "key not found")]
+fn test_map_get_unchecked_missing() {
+    let env = Env::default();
+    let map: Map<u32, u32> = Map::new(&env);
+    map.get_unchecked(1);
+}
+
+#[test]
+fn test_map_non_copy_values() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let mut map = Map::new(&env);
+    map.set(owner, Vec::from([1u32, 2]));
+    let map = Map::<Address, Vec<u32>>::from_val(map.to_val()).unwrap();
+    assert_eq!(map.get(owner).unwrap().len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Map capacity exceeded")]
+fn test_map_capacity_exceeded() {
+    let env = Env::default();
+    let mut map = Map::new(&env);
+    for i in 0..=soroban_sdk::vec::capacity() {
+        map.set(i, i);
+    }
+}
+
+#[test]
+fn test_map_replace_at_capacity() {
+    let env = Env::default();
+    let mut map = Map::new(&env);
+    for i in 0..soroban_sdk::vec::capacity() {
+        map.set(i, i);
+    }
+    map.set(0, 1);
+    assert_eq!(map.get(0), Some(1));
+}
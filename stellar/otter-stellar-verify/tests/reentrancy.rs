@@ -0,0 +1,220 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+use callback::{Callback, CallbackClient};
+use exchange::{Exchange, ExchangeClient};
+use hook::HookClient;
+use ledger::{Ledger, LedgerClient};
+use vault::{Vault, VaultClient};
+
+mod vault {
+    use super::*;
+
+    #[contract]
+    pub struct Vault;
+
+    #[contractimpl]
+    impl Vault {
+        /// Notifies `callback`, which may call back into the vault.
+        pub fn withdraw(env: Env, callback: Address, reenter: bool) -> u32 {
+            let vault = env.current_contract_address();
+            CallbackClient::new(&env, &callback).on_withdraw(&vault, &reenter)
+        }
+
+        pub fn balance(_env: Env) -> u32 {
+            100
+        }
+    }
+}
+
+mod callback {
+    use super::*;
+
+    #[contract]
+    pub struct Callback;
+
+    #[contractimpl]
+    impl Callback {
+        pub fn on_withdraw(env: Env, vault: Address, reenter: bool) -> u32 {
+            let depth = env.call_stack().len() as u32;
+            if reenter {
+                VaultClient::new(&env, &vault).balance();
+            }
+            depth
+        }
+    }
+}
+
+mod ledger {
+    use super::*;
+
+    #[contract]
+    pub struct Ledger;
+
+    #[contractimpl]
+    impl Ledger {
+        /// Adds `amount` to the total. Anyone can call it.
+        pub fn record(env: Env, amount: u32) {
+            let total = Self::total(env.clone()).wrapping_add(amount);
+            env.storage().instance().set(&Symbol::from("total"), &total);
+        }
+
+        pub fn total(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&Symbol::from("total"))
+                .unwrap_or(0)
+        }
+    }
+}
+
+mod hook {
+    use super::*;
+
+    /// A hook supplied by the user, whose code can't be trusted.
+    #[contract]
+    pub struct Hook;
+
+    #[contractimpl]
+    impl Hook {
+        pub fn on_settle(_env: Env) {}
+    }
+}
+
+mod exchange {
+    use super::*;
+
+    #[contract]
+    pub struct Exchange;
+
+    #[contractimpl]
+    impl Exchange {
+        /// Records a trade in `ledger`, returning the total it expects the
+        /// ledger to have. The total is read before `hook` runs, so it goes
+        /// stale if the hook records a trade itself.
+        pub fn settle(env: Env, ledger: Address, hook: Address) -> u32 {
+            let ledger = LedgerClient::new(&env, &ledger);
+            let expected = ledger.total().wrapping_add(1);
+            HookClient::new(&env, &hook).on_settle();
+            ledger.record(&1);
+            expected
+        }
+
+        /// Like `settle`, but only reads the total once `hook` has run.
+        pub fn settle_checked(env: Env, ledger: Address, hook: Address) -> u32 {
+            HookClient::new(&env, &hook).on_settle();
+            let ledger = LedgerClient::new(&env, &ledger);
+            let expected = ledger.total().wrapping_add(1);
+            ledger.record(&1);
+            expected
+        }
+    }
+}
+
+#[test]
+fn test_call_stack() {
+    let env = Env::default();
+    let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+    let callback = env.register_contract(None, Callback);
+
+    assert_eq!(vault.withdraw(&callback, &false), 2);
+    assert!(env.call_stack().is_empty());
+}
+
+#[test]
+fn test_call_stack_frames() {
+    let env = Env::default();
+    let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+
+    let stack = env.with_invocation(
+        soroban_sdk::dispatch::Invocation {
+            contract: vault.address,
+            fn_name: Symbol::from("withdraw"),
+            args: soroban_sdk::Vec::new(&env),
+        },
+        || env.call_stack(),
+    );
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack[0].contract, vault.address);
+}
+
+#[test]
+#[should_panic(expected = "Contract re-entry is not allowed")]
+fn test_reentry_panics() {
+    let env = Env::default();
+    let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+    let callback = env.register_contract(None, Callback);
+
+    vault.withdraw(&callback, &true);
+}
+
+#[test]
+fn test_call_stack_after_panic() {
+    let env = Env::default();
+    let vault = VaultClient::new(&env, &env.register_contract(None, Vault));
+    let callback = env.register_contract(None, Callback);
+    let current = env.current_contract_address();
+
+    let reentered = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vault.withdraw(&callback, &true)
+    }));
+    assert!(reentered.is_err());
+    assert!(env.call_stack().is_empty());
+    assert_eq!(env.current_contract_address(), current);
+    assert_eq!(vault.withdraw(&callback, &false), 2);
+}
+
+#[test]
+fn test_settle() {
+    let env = Env::default();
+    let ledger = env.register_contract(None, Ledger);
+    let exchange = ExchangeClient::new(&env, &env.register_contract(None, Exchange));
+    let hook = Address::new(&env);
+
+    assert_eq!(exchange.settle(&ledger, &hook), 1);
+    assert_eq!(exchange.settle_checked(&ledger, &hook), 2);
+    assert_eq!(LedgerClient::new(&env, &ledger).total(), 2);
+}
+
+#[test]
+fn test_contract_functions() {
+    use soroban_sdk::dispatch::ContractFunctionSet;
+
+    assert_eq!(Vault.functions(), ["withdraw", "balance"]);
+    assert_eq!(Callback.functions(), ["on_withdraw"]);
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    /// The hook can record a trade of its own while `settle` runs.
+    #[kani::proof]
+    #[kani::unwind(10)]
+    #[kani::should_panic]
+    fn verify_settle_stale_total() {
+        let env = Env::default();
+        env.enable_adversarial_callees();
+        let ledger = env.register_contract(None, Ledger);
+        let exchange = ExchangeClient::new(&env, &env.register_contract(None, Exchange));
+        // Never registered, so its calls are untrusted.
+        let hook = Address::new(&env);
+
+        let expected = exchange.settle(&ledger, &hook);
+        assert_eq!(LedgerClient::new(&env, &ledger).total(), expected);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn verify_settle_checked() {
+        let env = Env::default();
+        env.enable_adversarial_callees();
+        let ledger = env.register_contract(None, Ledger);
+        let exchange = ExchangeClient::new(&env, &env.register_contract(None, Exchange));
+        // Never registered, so its calls are untrusted.
+        let hook = Address::new(&env);
+
+        let expected = exchange.settle_checked(&ledger, &hook);
+        assert_eq!(LedgerClient::new(&env, &ledger).total(), expected);
+    }
+}
@@ -0,0 +1,95 @@
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+use first::{First, FirstClient};
+use second::{Second, SecondClient};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+
+mod first {
+    use super::*;
+
+    #[contract]
+    pub struct First;
+
+    #[contractimpl]
+    impl First {
+        pub fn set_admin(env: Env, admin: Address) {
+            env.storage().instance().set(&ADMIN, &admin);
+        }
+
+        pub fn admin(env: Env) -> Option<Address> {
+            env.storage().instance().get(&ADMIN)
+        }
+    }
+}
+
+mod second {
+    use super::*;
+
+    #[contract]
+    pub struct Second;
+
+    #[contractimpl]
+    impl Second {
+        pub fn set_admin(env: Env, admin: Address) {
+            env.storage().instance().set(&ADMIN, &admin);
+        }
+
+        pub fn admin(env: Env) -> Option<Address> {
+            env.storage().instance().get(&ADMIN)
+        }
+    }
+}
+
+fn setup(env: &Env) -> (FirstClient<'static>, SecondClient<'static>) {
+    let first = env.register_contract(None, First);
+    let second = env.register_contract(None, Second);
+    (
+        FirstClient::new(env, &first),
+        SecondClient::new(env, &second),
+    )
+}
+
+#[test]
+fn test_storage_is_per_contract() {
+    let env = Env::default();
+    let (first, second) = setup(&env);
+    let admin = Address::generate(&env);
+
+    first.set_admin(&admin);
+    assert_eq!(first.admin(), Some(admin));
+    assert_eq!(second.admin(), None);
+
+    second.set_admin(&first.address);
+    assert_eq!(first.admin(), Some(admin));
+    assert_eq!(second.admin(), Some(first.address));
+}
+
+#[test]
+fn test_as_contract_seeds_storage() {
+    let env = Env::default();
+    let (first, second) = setup(&env);
+    let admin = Address::generate(&env);
+
+    env.as_contract(&first.address, || {
+        assert_eq!(env.current_contract_address(), first.address);
+        env.storage().instance().set(&ADMIN, &admin);
+    });
+    assert_eq!(env.current_contract_address(), second.address);
+    assert_eq!(first.admin(), Some(admin));
+    assert_eq!(second.admin(), None);
+}
+
+#[test]
+fn test_as_contract_reads_storage() {
+    let env = Env::default();
+    let (first, _) = setup(&env);
+    let admin = Address::generate(&env);
+
+    first.set_admin(&admin);
+    let stored: Option<Address> =
+        env.as_contract(&first.address, || env.storage().instance().get(&ADMIN));
+    assert_eq!(stored, Some(admin));
+    assert!(!env.storage().instance().has(&ADMIN));
+}
@@ -1,8 +1,21 @@
-use otter_stellar_verify::{token, Address, Env};
-use token::AdminClient as TokenAdminClient;
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, token, Address, Env, ToValEnum};
+use token::StellarAssetClient as TokenAdminClient;
 use token::TokenClient;
 
-fn create_token_contract(e: &Env, admin: Address) -> (TokenClient, TokenAdminClient) {
+/// Holds tokens that only it can move.
+#[contract]
+pub struct Vault;
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        let vault = env.current_contract_address();
+        TokenClient::new(&env, &token).transfer(&vault, &to, &amount);
+    }
+}
+
+fn create_token_contract<'a>(e: &Env, admin: Address) -> (TokenClient<'a>, TokenAdminClient<'a>) {
     let contract_address = e.register_stellar_asset_contract(admin);
     (
         TokenClient::new(e, &contract_address),
@@ -35,3 +48,216 @@ fn test_mint_and_transfer() {
     assert_eq!(token_b.balance(&a), 10);
     assert_eq!(token_b.balance(&b), 990);
 }
+
+#[test]
+fn test_contract_transfers_own_balance() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let token_admin = Address::new(&env);
+    let vault = env.register_contract(None, Vault);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&vault, &1000);
+
+    VaultClient::new(&env, &vault).withdraw(&token.address, &a, &100);
+    assert_eq!(token.balance(&vault), 900);
+    assert_eq!(token.balance(&a), 100);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_transfer_requires_auth() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let token_admin = Address::new(&env);
+    let vault = env.register_contract(None, Vault);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&vault, &1000);
+
+    token.transfer(&vault, &a, &100);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call")]
+fn test_transfer_from_requires_spender_auth() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let token_admin = Address::new(&env);
+    let spender = env.register_contract(None, Vault);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&a, &1000);
+    token.approve(&a, &spender, &500, &200);
+
+    token.transfer_from(&spender, &a, &a, &100);
+}
+
+#[test]
+#[should_panic(expected = "balance is deauthorized")]
+fn test_deauthorized_transfer() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let b = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&a, &1000);
+
+    token_admin.set_authorized(&a, &false);
+    assert!(!token_admin.authorized(&a));
+    assert!(token_admin.authorized(&b));
+
+    token.transfer(&a, &b, &10);
+}
+
+#[test]
+fn test_clawback_deauthorized() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&a, &1000);
+    token_admin.set_authorized(&a, &false);
+
+    token_admin.clawback(&a, &400);
+    assert_eq!(token.balance(&a), 600);
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|e| e.contract == token.address));
+    assert_eq!(events[2].data, 400i128.to_val());
+}
+
+#[test]
+fn test_allowance_expiration() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let b = Address::new(&env);
+    let spender = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&a, &1000);
+
+    env.ledger().set_sequence_number(100);
+    token.approve(&a, &spender, &500, &200);
+    assert_eq!(token.allowance(&a, &spender), 500);
+
+    token.transfer_from(&spender, &a, &b, &100);
+    assert_eq!(token.allowance(&a, &spender), 400);
+
+    env.ledger().set_sequence_number(201);
+    assert_eq!(token.allowance(&a, &spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "expiration_ledger is less than ledger seq")]
+fn test_approve_expired() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let spender = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, _) = create_token_contract(&env, token_admin);
+
+    env.ledger().set_sequence_number(100);
+    token.approve(&a, &spender, &0, &99);
+    token.approve(&a, &spender, &500, &99);
+}
+
+#[test]
+#[should_panic(expected = "negative amount is not allowed")]
+fn test_negative_transfer() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let b = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&b, &1000);
+
+    token.transfer(&a, &b, &-10);
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_mint_overflow() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (_, token_admin) = create_token_contract(&env, token_admin);
+    token_admin.mint(&a, &i128::MAX);
+    token_admin.mint(&a, &1);
+}
+
+#[test]
+fn test_total_supply() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let b = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin) = create_token_contract(&env, token_admin);
+    assert_eq!(token_admin.get_self_token().total_supply, 0);
+
+    token_admin.mint(&a, &1000);
+    token_admin.mint(&b, &500);
+    token.transfer(&a, &b, &300);
+    token.burn(&b, &100);
+    token_admin.clawback(&a, &200);
+
+    let supply = token_admin.get_self_token().total_supply;
+    assert_eq!(supply, 1200);
+    assert_eq!(supply, token.balance(&a) + token.balance(&b));
+}
+
+#[test]
+fn test_metadata() {
+    let env = Env::default();
+    let token_admin = Address::new(&env);
+
+    let (token, token_admin_client) = create_token_contract(&env, token_admin);
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.name().as_str(), "Stellar Lumens");
+    assert_eq!(token.symbol().as_str(), "XLM");
+    assert_eq!(token_admin_client.admin(), token_admin);
+}
+
+#[test]
+fn test_asset_client_token_functions() {
+    let env = Env::default();
+
+    let a = Address::new(&env);
+    let b = Address::new(&env);
+    let spender = Address::new(&env);
+    let token_admin = Address::new(&env);
+
+    let (_, token) = create_token_contract(&env, token_admin);
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.name().as_str(), "Stellar Lumens");
+    assert_eq!(token.symbol().as_str(), "XLM");
+
+    token.mint(&a, &1000);
+    token.transfer(&a, &b, &100);
+    token.burn(&b, &10);
+    token.approve(&a, &spender, &300, &200);
+    token.transfer_from(&spender, &a, &b, &100);
+    token.burn_from(&spender, &a, &50);
+
+    assert_eq!(token.allowance(&a, &spender), 150);
+    assert_eq!(token.balance(&a), 750);
+    assert_eq!(token.balance(&b), 190);
+}
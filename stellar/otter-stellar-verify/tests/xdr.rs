@@ -0,0 +1,148 @@
+#![cfg(not(feature = "kani"))]
+
+extern crate alloc;
+
+use otter_stellar_verify as soroban_sdk;
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Bytes, BytesN, Env, FromXdr, Map, String, Symbol, ToXdr,
+    Vec, I256,
+};
+use stellar_xdr::curr::{
+    AccountId, Hash, Limits, PublicKey, ScAddress, ScSymbol, ScVal, Uint256, WriteXdr,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Allowance {
+    pub spender: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Allowance(Address, Address),
+}
+
+#[test]
+fn test_to_xdr_matches_host_encoding() {
+    let env = Env::default();
+    assert_eq!(7u32.to_xdr(&env).as_slice(), [0, 0, 0, 3, 0, 0, 0, 7]);
+    assert_eq!(
+        symbol_short!("abc").to_xdr(&env).as_slice(),
+        [0, 0, 0, 15, 0, 0, 0, 3, b'a', b'b', b'c', 0]
+    );
+    assert_eq!(
+        Vec::from([1u32, 2]).to_xdr(&env).as_slice(),
+        [0, 0, 0, 16, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 2]
+    );
+}
+
+#[test]
+fn test_xdr_roundtrip() {
+    let env = Env::default();
+
+    let amount = -5i128;
+    assert_eq!(i128::from_xdr(&env, &amount.to_xdr(&env)), Ok(amount));
+
+    let big = I256::from_i128(&env, i128::MIN).mul(&I256::from_i32(&env, 4));
+    assert_eq!(I256::from_xdr(&env, &big.to_xdr(&env)), Ok(big));
+
+    let name = String::from_str(&env, "Stellar Lumens");
+    assert_eq!(String::from_xdr(&env, &name.clone().to_xdr(&env)), Ok(name));
+
+    let owner = Address::generate(&env);
+    assert_eq!(Address::from_xdr(&env, &owner.to_xdr(&env)), Ok(owner));
+
+    let map = Map::from_array(&env, [(symbol_short!("a"), 1u32), (symbol_short!("b"), 2)]);
+    assert_eq!(
+        Map::<Symbol, u32>::from_xdr(&env, &map.clone().to_xdr(&env)),
+        Ok(map)
+    );
+}
+
+#[test]
+fn test_xdr_ignores_vec_capacity() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let xdr = owner.to_xdr(&env);
+    assert_eq!(xdr.len(), 40);
+    assert_eq!(Address::from_xdr(&env, &xdr), Ok(owner));
+
+    let signature = BytesN::from_array(&env, &[1; 64]);
+    assert_eq!(
+        BytesN::<64>::from_xdr(&env, &signature.clone().to_xdr(&env)),
+        Ok(signature)
+    );
+}
+
+fn network_address_xdr(sc_address: ScAddress) -> Bytes {
+    let xdr = ScVal::Address(sc_address).to_xdr(Limits::none()).unwrap();
+    Bytes(Vec::from_slice_unbounded(&xdr))
+}
+
+#[test]
+fn test_network_addresses_roundtrip() {
+    let env = Env::default();
+    let account = network_address_xdr(ScAddress::Account(AccountId(
+        PublicKey::PublicKeyTypeEd25519(Uint256([9; 32])),
+    )));
+    let contract = network_address_xdr(ScAddress::Contract(Hash([9; 32])));
+
+    let account_address = Address::from_xdr(&env, &account).unwrap();
+    let contract_address = Address::from_xdr(&env, &contract).unwrap();
+    assert_ne!(account_address, contract_address);
+    assert_eq!(Address::from_xdr(&env, &account), Ok(account_address));
+    assert_eq!(account_address.to_xdr(&env), account);
+    assert_eq!(contract_address.to_xdr(&env), contract);
+}
+
+#[test]
+fn test_from_xdr_rejects_invalid_input() {
+    let env = Env::default();
+    let truncated = Bytes::from_array(&env, &[0, 0, 0, 3, 0, 0]);
+    assert!(u32::from_xdr(&env, &truncated).is_err());
+    let symbol = symbol_short!("abc").to_xdr(&env);
+    assert!(u32::from_xdr(&env, &symbol).is_err());
+}
+
+fn host_value(xdr: &Bytes) -> ScVal {
+    <ScVal as stellar_xdr::curr::ReadXdr>::from_xdr(xdr.as_slice(), Limits::none()).unwrap()
+}
+
+fn symbol(s: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+}
+
+#[test]
+fn test_contract_types_as_host_values() {
+    let env = Env::default();
+    let (from, spender) = (Address::generate(&env), Address::generate(&env));
+
+    let allowance = Allowance {
+        spender,
+        amount: 10,
+    };
+    let ScVal::Map(Some(fields)) = host_value(&allowance.clone().to_xdr(&env)) else {
+        panic!("expected a map");
+    };
+    let keys: std::vec::Vec<_> = fields.iter().map(|entry| entry.key.clone()).collect();
+    assert_eq!(keys, [symbol("amount"), symbol("spender")]);
+    assert_eq!(
+        Allowance::from_xdr(&env, &allowance.clone().to_xdr(&env)),
+        Ok(allowance)
+    );
+
+    let key = DataKey::Allowance(from, spender);
+    let ScVal::Vec(Some(items)) = host_value(&key.clone().to_xdr(&env)) else {
+        panic!("expected a vec");
+    };
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0], symbol("Allowance"));
+    assert_eq!(DataKey::from_xdr(&env, &key.clone().to_xdr(&env)), Ok(key));
+    assert_eq!(
+        DataKey::from_xdr(&env, &DataKey::Admin.to_xdr(&env)),
+        Ok(DataKey::Admin)
+    );
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::{Env, FromValEnum, ToValEnum};
+use crate::{auth, Env, FromValEnum, ToValEnum, Val, Vec};
 
 #[derive(Debug, Hash, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
 pub struct Address {
@@ -10,7 +10,9 @@ pub struct Address {
 impl From<crate::Val> for Address {
     fn from(val: crate::Val) -> Self {
         match val {
-            crate::Val::AddressObj(address) => Address { val: address as u8 },
+            crate::Val::AddressObj(address) => Address {
+                val: u8::try_from(address).expect("address id out of range"),
+            },
             _ => panic!("Error"),
         }
     }
@@ -23,8 +25,27 @@ impl Display for Address {
 }
 
 impl Address {
-    pub fn require_auth_for_args(&self, _args: (Address, Address, i128, i128)) {}
-    pub fn require_auth(&self) {}
+    /// Requires this address to have authorized the current contract call
+    /// with exactly `args`, and records the requirement for
+    /// [`Env::auths`].
+    ///
+    /// ### Panics
+    ///
+    /// Outside of a contract call, like [`Address::require_auth`].
+    pub fn require_auth_for_args(&self, args: Vec<Val>) {
+        auth::require_auth(*self, Some(args));
+    }
+
+    /// Requires this address to have authorized the current contract call
+    /// with its arguments.
+    ///
+    /// ### Panics
+    ///
+    /// Outside of a contract call. Call contract functions through their
+    /// client, or run them with `Env::as_contract`.
+    pub fn require_auth(&self) {
+        auth::require_auth(*self, None);
+    }
 
     pub fn to_le_bytes(&self) -> [u8; 1] {
         self.val.to_le_bytes()
@@ -46,7 +67,8 @@ impl ToValEnum for Address {
 impl FromValEnum for Address {
     fn from_val(val: crate::Val) -> Option<Self> {
         if let crate::Val::AddressObj(u) = val {
-            Some(Address { val: u as u8 })
+            // Ids that don't fit an `Address` were never handed out.
+            u8::try_from(u).ok().map(|val| Address { val })
         } else {
             None
         }
@@ -0,0 +1,462 @@
+use std::{
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::{dispatch::Invocation, Address, Env, Symbol, ToValEnum, Val, Vec};
+
+/// An authorization required from `address` while `contract` was executing,
+/// together with the exact arguments it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthRecord {
+    pub address: Address,
+    pub contract: Address,
+    pub args: Vec<Val>,
+}
+
+/// A host function call that requires authorization.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthorizedFunction {
+    /// A call to a contract function.
+    Contract(Invocation),
+    /// The creation of a contract, with the `Val` forms of its
+    /// `ContractExecutable` and salt.
+    CreateContract { executable: Val, salt: Val },
+}
+
+/// A host function call, together with the calls it makes, that an address
+/// signs off on, the model of a `SorobanAuthorizedInvocation`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuthorizedInvocation {
+    pub function: AuthorizedFunction,
+    pub sub_invocations: std::vec::Vec<AuthorizedInvocation>,
+}
+
+impl AuthorizedInvocation {
+    /// Returns whether `function` can be authorized after the calls in
+    /// `used`: it must be an unused call whose parent was used.
+    ///
+    /// Each call in `used` accounts for one call of the tree, in order, so
+    /// a tree authorizing the same call twice can be used twice.
+    fn can_use(&self, function: &AuthorizedFunction, used: &[AuthorizedFunction]) -> bool {
+        self.can_use_after(function, &mut used.iter().collect())
+    }
+
+    fn can_use_after(
+        &self,
+        function: &AuthorizedFunction,
+        used: &mut std::vec::Vec<&AuthorizedFunction>,
+    ) -> bool {
+        let Some(index) = used.iter().position(|call| **call == self.function) else {
+            return self.function == *function;
+        };
+        used.remove(index);
+        self.sub_invocations
+            .iter()
+            .any(|sub_invocation| sub_invocation.can_use_after(function, used))
+    }
+
+    fn collect_unused(
+        &self,
+        used: &mut std::vec::Vec<&AuthorizedFunction>,
+        unused: &mut std::vec::Vec<AuthorizedFunction>,
+    ) {
+        match used.iter().position(|call| **call == self.function) {
+            Some(index) => {
+                used.remove(index);
+            }
+            None => unused.push(self.function.clone()),
+        }
+        for sub_invocation in &self.sub_invocations {
+            sub_invocation.collect_unused(used, unused);
+        }
+    }
+
+    fn collect_contexts(&self, contexts: &mut Vec<Rc<Val>>) {
+        contexts.push(Rc::new(context_val(&self.function)));
+        for sub_invocation in &self.sub_invocations {
+            sub_invocation.collect_contexts(contexts);
+        }
+    }
+}
+
+/// A signed authorization of `root_invocation` by `address`, the model of
+/// a `SorobanAuthorizationEntry`.
+///
+/// `signature` is passed to the `__check_auth` of custom account contracts.
+/// Each `nonce` can be used by one entry only, and the entry stops being
+/// valid after `signature_expiration_ledger`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationEntry {
+    pub address: Address,
+    pub nonce: i64,
+    pub signature_expiration_ledger: u32,
+    pub root_invocation: AuthorizedInvocation,
+    pub signature: Val,
+}
+
+impl AuthorizationEntry {
+    /// Returns the payload `signature` has to sign on the network of `env`.
+    pub fn signature_payload(&self, env: &Env) -> [u8; 32] {
+        signature_payload(
+            &env.ledger_info().network_id,
+            self.nonce,
+            self.signature_expiration_ledger,
+            &self.root_invocation,
+        )
+    }
+}
+
+/// An authorization entry whose nonce was consumed by authorizing its root
+/// invocation. The calls beneath the root are authorized by the same entry
+/// while the root invocation is running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumedNonce {
+    pub address: Address,
+    pub nonce: i64,
+    pub root_invocation: AuthorizedInvocation,
+    /// The calls authorized so far, starting with the root invocation.
+    pub used: std::vec::Vec<AuthorizedFunction>,
+    /// Whether the root invocation is still running.
+    pub active: bool,
+    pub(crate) depth: usize,
+}
+
+/// Calls deeper in the call stack that `contract` authorized through
+/// `Env::authorize_as_current_contract`, the model of an
+/// `InvokerContractAuthEntry`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAuthorization {
+    pub contract: Address,
+    pub root_invocation: AuthorizedInvocation,
+    /// The calls authorized so far.
+    pub used: std::vec::Vec<AuthorizedFunction>,
+    /// Whether the invocation of `contract` that granted the authorization
+    /// is still running.
+    pub active: bool,
+    pub(crate) depth: usize,
+}
+
+impl ContractAuthorization {
+    /// Returns the authorized calls that were never made, which means the
+    /// authorization was broader than needed.
+    pub fn unused(&self) -> std::vec::Vec<AuthorizedFunction> {
+        let mut unused = std::vec::Vec::new();
+        self.root_invocation
+            .collect_unused(&mut self.used.iter().collect(), &mut unused);
+        unused
+    }
+}
+
+/// Records that `address` must authorize the current invocation and checks
+/// that it did.
+///
+/// `args` defaults to the arguments of the current invocation.
+///
+/// Panics outside of a contract call, where the host has nothing to
+/// authorize: call the contract through its client or `Env::as_contract`.
+pub(crate) fn require_auth(address: Address, args: Option<Vec<Val>>) {
+    let env = Env::active().expect("require_auth called outside of a contract call");
+    let invocation = env.current_invocation();
+    let contract = env.current_contract_address();
+    let (fn_name, args) = match invocation {
+        Some(invocation) if invocation.contract == contract => {
+            (invocation.fn_name, args.unwrap_or(invocation.args))
+        }
+        _ => (Symbol::default(), args.unwrap_or_else(|| Vec::new(&env))),
+    };
+    env.auths.borrow_mut().push(AuthRecord {
+        address,
+        contract,
+        args: args.clone(),
+    });
+
+    check_auth(
+        &env,
+        address,
+        AuthorizedFunction::Contract(Invocation {
+            contract,
+            fn_name,
+            args,
+        }),
+    );
+}
+
+/// Checks that `deployer` authorized creating a contract from `executable`
+/// with `salt`.
+pub fn require_create_contract_auth(env: &Env, deployer: Address, executable: Val, salt: Val) {
+    check_auth(
+        env,
+        deployer,
+        AuthorizedFunction::CreateContract { executable, salt },
+    );
+}
+
+/// Authorizes `function` for `address` the way the host does:
+///
+/// - the contract that directly made the call is implicitly authorized,
+/// - a contract can authorize deeper calls with
+///   `Env::authorize_as_current_contract`,
+/// - accounts are assumed to have signed,
+/// - custom account contracts decide in `__check_auth`, based on an
+///   authorization entry whose root invocation is the call; the calls
+///   beneath it are then authorized by the same entry while it runs.
+fn check_auth(env: &Env, address: Address, function: AuthorizedFunction) {
+    if env.mock_auths.get() {
+        return;
+    }
+    let caller = match function {
+        AuthorizedFunction::Contract(_) => env.invoker(),
+        AuthorizedFunction::CreateContract { .. } => Some(env.current_contract_address()),
+    };
+    if caller == Some(address)
+        || use_contract_authorization(env, address, &function)
+        || !env.has_contract(&address)
+        || use_signed_authorization(env, address, &function)
+    {
+        return;
+    }
+
+    let entry = env
+        .auth_entries
+        .borrow()
+        .iter()
+        .filter(|entry| entry.address == address && entry.root_invocation.function == function)
+        // The first entry whose nonce wasn't used yet, if any.
+        .min_by_key(|entry| nonce_consumed(env, entry))
+        .cloned()
+        .unwrap_or_else(|| panic!("Unauthorized function call for address {}", address));
+
+    if env.ledger_info().sequence_number > entry.signature_expiration_ledger {
+        panic!("signature has expired");
+    }
+    consume_nonce(env, &entry);
+
+    let mut auth_contexts = Vec::new(env);
+    entry.root_invocation.collect_contexts(&mut auth_contexts);
+    let payload = entry.signature_payload(env);
+    let args = [
+        Val::BytesNVal(Vec::new_from_slice(&payload)),
+        entry.signature,
+        Val::VecVal(auth_contexts),
+    ];
+    if env
+        .call_contract(&address, &Symbol::from("__check_auth"), &args)
+        .is_none()
+    {
+        panic!("Unauthorized function call for address {}", address);
+    }
+}
+
+/// Authorizes `function` through the entry of `address` whose root
+/// invocation is running, if `function` is one of its unused calls.
+fn use_signed_authorization(env: &Env, address: Address, function: &AuthorizedFunction) -> bool {
+    let mut consumed = env.consumed_nonces.borrow_mut();
+    let entry = consumed.iter_mut().find(|entry| {
+        entry.active
+            && entry.address == address
+            && entry.root_invocation.can_use(function, &entry.used)
+    });
+    match entry {
+        Some(entry) => {
+            entry.used.push(function.clone());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Authorizes `function` through an active `authorize_as_current_contract`
+/// of `contract`, if one covers it.
+fn use_contract_authorization(env: &Env, contract: Address, function: &AuthorizedFunction) -> bool {
+    let mut authorizations = env.contract_auths.borrow_mut();
+    let authorization = authorizations.iter_mut().find(|authorization| {
+        authorization.active
+            && authorization.contract == contract
+            && authorization
+                .root_invocation
+                .can_use(function, &authorization.used)
+    });
+    match authorization {
+        Some(authorization) => {
+            authorization.used.push(function.clone());
+            true
+        }
+        None => false,
+    }
+}
+
+fn nonce_consumed(env: &Env, entry: &AuthorizationEntry) -> bool {
+    env.consumed_nonces
+        .borrow()
+        .iter()
+        .any(|used| used.address == entry.address && used.nonce == entry.nonce)
+}
+
+/// Marks the nonce of `entry` as used by authorizing its root invocation,
+/// which no entry of the same address may do again with that nonce.
+fn consume_nonce(env: &Env, entry: &AuthorizationEntry) {
+    if nonce_consumed(env, entry) {
+        panic!("nonce already used");
+    }
+    env.consumed_nonces.borrow_mut().push(ConsumedNonce {
+        address: entry.address,
+        nonce: entry.nonce,
+        root_invocation: entry.root_invocation.clone(),
+        used: std::vec![entry.root_invocation.function.clone()],
+        active: true,
+        depth: env.invocations.borrow().len(),
+    });
+}
+
+/// Derives the payload signed by an authorization entry from the network
+/// id, its nonce, expiration ledger and invocation tree.
+///
+/// The host takes the SHA-256 of the XDR preimage; this model only keeps
+/// the property that matters for verification: different entries get
+/// different payloads.
+pub fn signature_payload(
+    network_id: &[u8; 32],
+    nonce: i64,
+    signature_expiration_ledger: u32,
+    root_invocation: &AuthorizedInvocation,
+) -> [u8; 32] {
+    let mut payload = [0u8; 32];
+    for (lane, chunk) in payload.chunks_mut(8).enumerate() {
+        let mut hasher = PayloadHasher::default();
+        (
+            lane,
+            network_id,
+            nonce,
+            signature_expiration_ledger,
+            root_invocation,
+        )
+            .hash(&mut hasher);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    payload
+}
+
+/// FNV-1a, which unlike the std `DefaultHasher` is the same everywhere.
+struct PayloadHasher(u64);
+
+impl Default for PayloadHasher {
+    fn default() -> Self {
+        PayloadHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for PayloadHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// The `Val` form of the `auth::Context` of `function`.
+pub fn context_val(function: &AuthorizedFunction) -> Val {
+    let variant = match function {
+        AuthorizedFunction::Contract(_) => "Contract",
+        AuthorizedFunction::CreateContract { .. } => "CreateContractHostFn",
+    };
+    Val::VecVal(Vec::new_from_slice(&[
+        Rc::new(Symbol::from(variant).to_val()),
+        Rc::new(context_fields_val(function)),
+    ]))
+}
+
+/// The `Val` form of the `ContractContext` or `CreateContractHostFnContext`
+/// wrapped in the `auth::Context` of `function`.
+pub fn context_fields_val(function: &AuthorizedFunction) -> Val {
+    let fields = match function {
+        AuthorizedFunction::Contract(invocation) => std::vec![
+            (Symbol::from("args").to_val(), invocation.args.to_val()),
+            (
+                Symbol::from("contract").to_val(),
+                invocation.contract.to_val()
+            ),
+            (
+                Symbol::from("fn_name").to_val(),
+                invocation.fn_name.to_val()
+            ),
+        ],
+        AuthorizedFunction::CreateContract { executable, salt } => std::vec![
+            (Symbol::from("executable").to_val(), executable.clone()),
+            (Symbol::from("salt").to_val(), salt.clone()),
+        ],
+    };
+    Val::MapVal(fields)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{dispatch::ContractFunctionSet, Env, IntoVal, Symbol, ToValEnum};
+
+    use super::*;
+
+    struct Account;
+
+    impl ContractFunctionSet for Account {
+        fn call(&self, _env: &Env, _func: &Symbol, _args: &[Val]) -> Option<Val> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_require_auth_for_args() {
+        let env = Env::default();
+        let user = Address::new(&env);
+        let other = Address::new(&env);
+
+        let contract = Address::new(&env);
+        env.as_contract(&contract, || {
+            user.require_auth_for_args((other, 10i128, Symbol::from("swap")).into_val(&env))
+        });
+
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].address, user);
+        assert_eq!(auths[0].args.len(), 3);
+        assert_eq!(auths[0].args[0], other.to_val());
+        assert_eq!(auths[0].args[1], Val::I128(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized function call")]
+    fn test_require_auth_checks_env_of_call() {
+        let env = Env::default();
+        let account = env.register_contract(None, Account);
+        let contract = Address::new(&env);
+        // Registering in another `Env` doesn't change where calls are checked.
+        Env::default().register_contract(None, Account);
+
+        env.as_contract(&contract, || account.require_auth());
+    }
+
+    #[test]
+    fn test_auths_cover_last_invocation_of_env() {
+        let env = Env::default();
+        let other_env = Env::default();
+        let user = Address::new(&env);
+        let contract = Address::new(&env);
+        let invocation = |fn_name: &str| Invocation {
+            contract,
+            fn_name: Symbol::from(fn_name),
+            args: Vec::new(&env),
+        };
+
+        env.with_invocation(invocation("deposit"), || user.require_auth());
+        assert_eq!(env.auths().len(), 1);
+        assert!(other_env.auths().is_empty());
+
+        env.with_invocation(invocation("balance"), || ());
+        assert!(env.auths().is_empty());
+    }
+}
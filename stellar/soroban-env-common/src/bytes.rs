@@ -0,0 +1,501 @@
+use std::ops::RangeBounds;
+
+use crate::{ConversionError, Env, FromValEnum, ToValEnum, Val, Vec};
+
+/// A growable array of bytes, bounded by [`crate::vec::capacity`] like `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+#[track_caller]
+fn index_out_of_bounds() -> ! {
+    panic!("index out of bounds")
+}
+
+impl Bytes {
+    pub fn new(env: &Env) -> Self {
+        Self(Vec::new(env))
+    }
+
+    pub fn from_array<const N: usize>(_env: &Env, items: &[u8; N]) -> Self {
+        Bytes(Vec::new_from_slice(items))
+    }
+
+    pub fn from_slice(_env: &Env, items: &[u8]) -> Self {
+        Bytes(Vec::new_from_slice(items))
+    }
+
+    pub fn env(&self) -> Env {
+        Env::default()
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    pub fn from_le_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Sets the byte at position `i`.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out of bounds.
+    #[track_caller]
+    pub fn set(&mut self, i: u32, v: u8) {
+        self.0.set(i, v);
+    }
+
+    pub fn get(&self, i: u32) -> Option<u8> {
+        self.0.get(i)
+    }
+
+    #[track_caller]
+    pub fn get_unchecked(&self, i: u32) -> u8 {
+        self.0.get_unchecked(i)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn first(&self) -> Option<u8> {
+        self.0.first()
+    }
+
+    #[track_caller]
+    pub fn first_unchecked(&self) -> u8 {
+        self.0.first_unchecked()
+    }
+
+    pub fn last(&self) -> Option<u8> {
+        self.0.last()
+    }
+
+    #[track_caller]
+    pub fn last_unchecked(&self) -> u8 {
+        self.0.last_unchecked()
+    }
+
+    pub fn remove(&mut self, i: u32) -> Option<()> {
+        self.0.remove(i)
+    }
+
+    #[track_caller]
+    pub fn remove_unchecked(&mut self, i: u32) {
+        self.0.remove_unchecked(i);
+    }
+
+    pub fn push_back(&mut self, x: u8) {
+        self.0.push_back(x);
+    }
+
+    pub fn pop_back(&mut self) -> Option<u8> {
+        self.0.pop_back()
+    }
+
+    #[track_caller]
+    pub fn pop_back_unchecked(&mut self) -> u8 {
+        self.0.pop_back_unchecked()
+    }
+
+    /// Inserts a byte at position `i`, shifting the bytes after it back.
+    ///
+    /// ### Panics
+    ///
+    /// If `i` is greater than the length.
+    #[track_caller]
+    pub fn insert(&mut self, i: u32, b: u8) {
+        self.0.insert(i, b);
+    }
+
+    /// Inserts `bytes` at position `i`, shifting the bytes after it back.
+    ///
+    /// ### Panics
+    ///
+    /// If `i` is greater than the length.
+    #[track_caller]
+    pub fn insert_from_bytes(&mut self, i: u32, bytes: Bytes) {
+        self.insert_from_slice(i, bytes.as_slice());
+    }
+
+    #[track_caller]
+    pub fn insert_from_array<const N: usize>(&mut self, i: u32, array: &[u8; N]) {
+        self.insert_from_slice(i, array);
+    }
+
+    #[track_caller]
+    pub fn insert_from_slice(&mut self, i: u32, slice: &[u8]) {
+        if i > self.len() {
+            index_out_of_bounds();
+        }
+        for (offset, b) in slice.iter().enumerate() {
+            self.0.insert(i + offset as u32, *b);
+        }
+    }
+
+    pub fn append(&mut self, other: &Bytes) {
+        self.0.extend_from_slice(other.as_slice());
+    }
+
+    pub fn extend_from_array<const N: usize>(&mut self, array: &[u8; N]) {
+        self.0.extend_from_slice(array);
+    }
+
+    pub fn extend_from_slice(&mut self, slice: &[u8]) {
+        self.0.extend_from_slice(slice);
+    }
+
+    /// Copies `slice` over the bytes starting at position `i`, growing the
+    /// bytes if the slice goes past the end.
+    ///
+    /// ### Panics
+    ///
+    /// If `i` is greater than the length.
+    #[track_caller]
+    pub fn copy_from_slice(&mut self, i: u32, slice: &[u8]) {
+        if i > self.len() {
+            index_out_of_bounds();
+        }
+        let overlap = slice.len().min((self.len() - i) as usize);
+        let (inside, outside) = slice.split_at(overlap);
+        self.0[i as usize..i as usize + overlap].copy_from_slice(inside);
+        self.0.extend_from_slice(outside);
+    }
+
+    /// Copies the bytes into `slice`.
+    ///
+    /// ### Panics
+    ///
+    /// If `slice` is not the same length as the bytes.
+    #[track_caller]
+    pub fn copy_into_slice(&self, slice: &mut [u8]) {
+        if slice.len() != self.len() as usize {
+            panic!("slice length does not match bytes length");
+        }
+        slice.copy_from_slice(self.as_slice());
+    }
+
+    /// Returns the bytes in range `r`.
+    ///
+    /// ### Panics
+    ///
+    /// If the range is out of bounds or its start is after its end.
+    #[track_caller]
+    pub fn slice(&self, r: impl RangeBounds<u32>) -> Self {
+        Bytes(self.0.slice(r))
+    }
+
+    /// Copies the bytes into a buffer of `B` bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If there are more than `B` bytes.
+    #[track_caller]
+    pub fn to_buffer<const B: usize>(&self) -> BytesBuffer<B> {
+        let len = self.len() as usize;
+        if len > B {
+            panic!("bytes don't fit in a buffer of {} bytes", B);
+        }
+        let mut buffer = [0; B];
+        buffer[..len].copy_from_slice(self.as_slice());
+        BytesBuffer { buffer, len }
+    }
+
+    pub fn iter(&self) -> crate::vec::VecIterator<'_, u8> {
+        self.0.iter()
+    }
+}
+
+impl ToValEnum for Bytes {
+    fn to_val(&self) -> Val {
+        Val::BytesNVal(self.0.clone())
+    }
+}
+
+impl FromValEnum for Bytes {
+    fn from_val(val: Val) -> Option<Self> {
+        if let Val::BytesNVal(u) = val {
+            Some(Bytes(u))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl kani::Arbitrary for Bytes {
+    fn any() -> Self {
+        let len: usize = kani::any();
+        kani::assume(len <= crate::vec::capacity());
+        let mut v = Vec::new(&Env::default());
+        for _ in 0..len {
+            v.push(kani::any());
+        }
+        Bytes(v)
+    }
+}
+
+/// Bytes copied into a fixed-size buffer by [`Bytes::to_buffer`].
+pub struct BytesBuffer<const B: usize> {
+    buffer: [u8; B],
+    len: usize,
+}
+
+impl<const B: usize> BytesBuffer<B> {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.buffer[..self.len]
+    }
+}
+
+/// Exactly `N` bytes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BytesN<const N: usize>(pub Bytes);
+
+impl<const N: usize> Default for BytesN<N> {
+    fn default() -> Self {
+        BytesN::from_array(&Env::default(), &[0; N])
+    }
+}
+
+impl<const N: usize> ToValEnum for BytesN<N> {
+    fn to_val(&self) -> Val {
+        self.0.to_val()
+    }
+}
+
+impl<const N: usize> FromValEnum for BytesN<N> {
+    fn from_val(val: Val) -> Option<Self> {
+        Bytes::from_val(val)?.try_into().ok()
+    }
+}
+
+impl<const N: usize> From<Val> for BytesN<N> {
+    fn from(val: Val) -> Self {
+        BytesN::from_val(val).expect("Error")
+    }
+}
+
+impl<const N: usize> BytesN<N> {
+    /// Creates a `BytesN` from `items`. The length is fixed by the type, so
+    /// it isn't bounded by the `Vec` capacity.
+    pub fn from_array(_env: &Env, items: &[u8; N]) -> Self {
+        BytesN(Bytes(Vec::from_slice_unbounded(items)))
+    }
+
+    pub fn env(&self) -> Env {
+        Env::default()
+    }
+
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        self.0.clone()
+    }
+
+    pub fn to_array(&self) -> [u8; N] {
+        let mut array = [0u8; N];
+        self.0.copy_into_slice(&mut array);
+        array
+    }
+
+    pub fn copy_into_slice(&self, slice: &mut [u8; N]) {
+        self.0.copy_into_slice(slice);
+    }
+
+    pub fn to_le_bytes(&self) -> [u8; N] {
+        self.to_array()
+    }
+
+    pub fn from_le_bytes(bytes: [u8; N]) -> Self {
+        Self::from_array(&Env::default(), &bytes)
+    }
+
+    /// Wraps `bytes` without checking its length.
+    pub fn unchecked_new(_env: Env, bytes: Vec<u8>) -> Self {
+        BytesN(Bytes(bytes))
+    }
+
+    /// Sets the byte at position `i`.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out of bounds.
+    #[track_caller]
+    pub fn set(&mut self, i: u32, v: u8) {
+        self.0.set(i, v);
+    }
+
+    pub fn get(&self, i: u32) -> Option<u8> {
+        self.0.get(i)
+    }
+
+    #[track_caller]
+    pub fn get_unchecked(&self, i: u32) -> u8 {
+        self.0.get_unchecked(i)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn len(&self) -> u32 {
+        N as u32
+    }
+
+    pub fn first(&self) -> Option<u8> {
+        self.0.first()
+    }
+
+    #[track_caller]
+    pub fn first_unchecked(&self) -> u8 {
+        self.0.first_unchecked()
+    }
+
+    pub fn last(&self) -> Option<u8> {
+        self.0.last()
+    }
+
+    #[track_caller]
+    pub fn last_unchecked(&self) -> u8 {
+        self.0.last_unchecked()
+    }
+
+    pub fn iter(&self) -> crate::vec::VecIterator<'_, u8> {
+        self.0.iter()
+    }
+}
+
+impl<const N: usize> From<BytesN<N>> for Bytes {
+    fn from(item: BytesN<N>) -> Self {
+        item.0
+    }
+}
+
+impl<const N: usize> From<&BytesN<N>> for Bytes {
+    fn from(item: &BytesN<N>) -> Self {
+        item.0.clone()
+    }
+}
+
+impl<const N: usize> From<BytesN<N>> for [u8; N] {
+    fn from(item: BytesN<N>) -> Self {
+        item.to_array()
+    }
+}
+
+impl<const N: usize> TryFrom<Bytes> for BytesN<N> {
+    type Error = ConversionError;
+
+    fn try_from(bytes: Bytes) -> Result<Self, ConversionError> {
+        if bytes.len() as usize == N {
+            Ok(BytesN(bytes))
+        } else {
+            Err(ConversionError)
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&Bytes> for BytesN<N> {
+    type Error = ConversionError;
+
+    fn try_from(bytes: &Bytes) -> Result<Self, ConversionError> {
+        bytes.clone().try_into()
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl<const N: usize> kani::Arbitrary for BytesN<N> {
+    fn any() -> Self {
+        BytesN::from_array(&Env::default(), &kani::any::<[u8; N]>())
+    }
+}
+
+impl<const N: usize> From<Box<Val>> for BytesN<N> {
+    fn from(value: Box<Val>) -> Self {
+        BytesN::from_val(*value).expect("Err")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bytes_slices() {
+        let env = Env::default();
+        let mut bytes = Bytes::from_array(&env, &[1, 2, 3]);
+        bytes.extend_from_array(&[4, 5]);
+        bytes.extend_from_slice(&[6]);
+        assert_eq!(bytes.slice(1..3).as_slice(), [2, 3]);
+        assert_eq!(bytes.slice(4..).as_slice(), [5, 6]);
+
+        bytes.copy_from_slice(5, &[7, 8]);
+        assert_eq!(bytes.as_slice(), [1, 2, 3, 4, 5, 7, 8]);
+        bytes.insert_from_array(1, &[9, 9]);
+        assert_eq!(bytes.as_slice(), [1, 9, 9, 2, 3, 4, 5, 7, 8]);
+
+        let mut out = [0u8; 9];
+        bytes.copy_into_slice(&mut out);
+        assert_eq!(out, [1, 9, 9, 2, 3, 4, 5, 7, 8]);
+        assert_eq!(bytes.to_buffer::<16>().as_slice(), out);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_bytes_slice_out_of_bounds() {
+        let bytes = Bytes::from_array(&Env::default(), &[1, 2, 3]);
+        bytes.slice(2..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_bytes_set_out_of_bounds() {
+        let mut bytes = Bytes::from_array(&Env::default(), &[1, 2, 3]);
+        bytes.set(3, 4);
+    }
+
+    #[test]
+    fn test_bytes_n_conversions() {
+        let env = Env::default();
+        let hash = BytesN::from_array(&env, &[7; 32]);
+        let bytes: Bytes = hash.clone().into();
+        assert_eq!(BytesN::<32>::try_from(&bytes), Ok(hash.clone()));
+        assert_eq!(BytesN::<64>::try_from(bytes), Err(ConversionError));
+        assert_eq!(hash.to_array(), [7; 32]);
+        assert_eq!(
+            BytesN::<32>::from_val(Bytes::from_array(&env, &[7; 32]).to_val()),
+            Some(hash)
+        );
+        assert_eq!(BytesN::<32>::from_val(Bytes::new(&env).to_val()), None);
+    }
+
+    #[test]
+    fn test_bytes_n_ignores_vec_capacity() {
+        let env = Env::default();
+        let signature = BytesN::<64>::from_array(&env, &[1; 64]);
+        assert!(crate::vec::capacity() < 64);
+        assert_eq!(signature.len(), 64);
+        assert_eq!(signature.to_array(), [1; 64]);
+        assert_eq!(BytesN::<64>::from_val(signature.to_val()), Some(signature));
+        assert_eq!(BytesN::<64>::default().to_array(), [0; 64]);
+    }
+}
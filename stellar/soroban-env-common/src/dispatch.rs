@@ -0,0 +1,203 @@
+//! Plumbing used by `#[contract]` and `#[contractimpl]` to call registered
+//! contracts by function name.
+//!
+//! Each `#[contractimpl]` block implements [`ContractFunctions`] for one
+//! slot, and `#[contract]` implements [`ContractFunctionSet`] by trying every
+//! slot in turn. Arguments are encoded with [`ToValEnum`], since the encoded
+//! arguments are what argument-level authorization matches against, so
+//! contract functions must take `Val`-representable arguments. Results and
+//! decoded arguments go through [`Conv`] so that functions returning types
+//! without a `Val` representation still compile; they only fail if they are
+//! actually called dynamically.
+//!
+//! Under Kani, functions can also be called with arbitrary arguments, which
+//! is how untrusted callees reach into registered contracts (see
+//! [`Env::enable_adversarial_callees`]).
+
+use core::marker::PhantomData;
+
+use crate::{Address, Env, FromValEnum, Symbol, ToValEnum, Val, Vec};
+
+/// Maximum number of `#[contractimpl]` blocks per contract.
+pub const MAX_IMPL_BLOCKS: usize = 64;
+
+/// The names of the functions of a contract.
+pub type FunctionNames = std::vec::Vec<&'static str>;
+
+/// A contract that can be invoked by function name.
+pub trait ContractFunctionSet {
+    /// Calls `func` with `args`, or returns `None` if the contract has no
+    /// such function.
+    fn call(&self, env: &Env, func: &Symbol, args: &[Val]) -> Option<Val>;
+
+    /// Returns the names of the functions the contract can be called with.
+    fn functions(&self) -> FunctionNames {
+        FunctionNames::new()
+    }
+
+    /// Calls `func` on the contract registered at `contract` with arbitrary
+    /// arguments, or returns `None` if the contract has no such function or
+    /// its arguments can't be generated.
+    fn call_arbitrary(&self, _env: &Env, _contract: &Address, _func: &Symbol) -> Option<Val> {
+        None
+    }
+}
+
+/// The functions of a single `#[contractimpl]` block.
+pub trait ContractFunctions<const SLOT: usize> {
+    fn call(env: &Env, func: &Symbol, args: &[Val]) -> Option<Val>;
+
+    fn functions() -> &'static [&'static str] {
+        &[]
+    }
+
+    fn call_arbitrary(_env: &Env, _contract: &Address, _func: &Symbol) -> Option<Val> {
+        None
+    }
+}
+
+/// A call into a contract, as seen by the host.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Invocation {
+    pub contract: Address,
+    pub fn_name: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[doc(hidden)]
+pub struct Slot<T, const SLOT: usize>(PhantomData<T>);
+
+impl<T, const SLOT: usize> Slot<T, SLOT> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Slot(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait SlotCall {
+    fn call(&self, env: &Env, func: &Symbol, args: &[Val]) -> Option<Val>;
+    fn functions(&self) -> &'static [&'static str];
+    fn call_arbitrary(&self, env: &Env, contract: &Address, func: &Symbol) -> Option<Val>;
+}
+
+impl<T: ContractFunctions<SLOT>, const SLOT: usize> SlotCall for &Slot<T, SLOT> {
+    fn call(&self, env: &Env, func: &Symbol, args: &[Val]) -> Option<Val> {
+        T::call(env, func, args)
+    }
+
+    fn functions(&self) -> &'static [&'static str] {
+        T::functions()
+    }
+
+    fn call_arbitrary(&self, env: &Env, contract: &Address, func: &Symbol) -> Option<Val> {
+        T::call_arbitrary(env, contract, func)
+    }
+}
+
+#[doc(hidden)]
+pub trait SlotCallFallback {
+    fn call(&self, _env: &Env, _func: &Symbol, _args: &[Val]) -> Option<Val> {
+        None
+    }
+
+    fn functions(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn call_arbitrary(&self, _env: &Env, _contract: &Address, _func: &Symbol) -> Option<Val> {
+        None
+    }
+}
+
+impl<T, const SLOT: usize> SlotCallFallback for Slot<T, SLOT> {}
+
+#[doc(hidden)]
+pub struct Conv<T>(PhantomData<T>);
+
+impl<T> Conv<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Conv(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ConvFromVal<T> {
+    fn decode(&self, val: &Val) -> T;
+}
+
+impl<T: FromValEnum> ConvFromVal<T> for &Conv<T> {
+    fn decode(&self, val: &Val) -> T {
+        T::from_val(val.clone()).expect("invalid argument type")
+    }
+}
+
+#[doc(hidden)]
+pub trait ConvFromValFallback<T> {
+    fn decode(&self, _val: &Val) -> T {
+        panic!("argument type can't be converted from a Val")
+    }
+}
+
+impl<T> ConvFromValFallback<T> for Conv<T> {}
+
+#[doc(hidden)]
+pub trait ConvToVal<T> {
+    fn encode(&self, value: &T) -> Val;
+}
+
+impl<T: ToValEnum> ConvToVal<T> for &Conv<T> {
+    fn encode(&self, value: &T) -> Val {
+        value.to_val()
+    }
+}
+
+#[doc(hidden)]
+pub trait ConvToValFallback<T> {
+    fn encode(&self, _value: &T) -> Val {
+        Val::Void
+    }
+}
+
+impl<T> ConvToValFallback<T> for Conv<T> {}
+
+#[cfg(any(kani, feature = "kani"))]
+#[doc(hidden)]
+pub trait ConvArbitrary<T> {
+    fn arbitrary(&self) -> T;
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl<T: kani::Arbitrary> ConvArbitrary<T> for &Conv<T> {
+    fn arbitrary(&self) -> T {
+        kani::any()
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+#[doc(hidden)]
+pub trait ConvArbitraryFallback<T> {
+    // Calls needing arguments that can't be generated are never made.
+    fn arbitrary(&self) -> T {
+        kani::assume(false);
+        unreachable!()
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl<T> ConvArbitraryFallback<T> for Conv<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Opaque;
+
+    #[test]
+    fn test_conv() {
+        assert_eq!((&&Conv::<u32>::new()).encode(&7), Val::U32Val(7));
+        assert_eq!((&&Conv::<u32>::new()).decode(&Val::U32Val(7)), 7);
+        assert_eq!(Conv::<Opaque>::new().encode(&Opaque), Val::Void);
+    }
+}
@@ -1,21 +1,171 @@
-use crate::{Address, FromValEnum, MockToken, Storage, Val};
+use crate::{
+    auth::{AuthorizationEntry, AuthorizedInvocation, ConsumedNonce, ContractAuthorization},
+    dispatch::{ContractFunctionSet, Invocation},
+    Address, AuthRecord, FromValEnum, MockToken, Storage, Symbol, ToValEnum, Val,
+};
 use std::fmt::Debug;
 use std::{
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     rc::Rc,
 };
+use stellar_xdr::curr::ScAddress;
+
+/// The function run when a contract is registered with [`Env::register`].
+const CONSTRUCTOR: &str = "__constructor";
+
+thread_local! {
+    // The `Env`s of the contract calls running on this thread, innermost
+    // last, for host functions like `require_auth` that are called without
+    // one.
+    static ACTIVE_ENVS: RefCell<Vec<Env>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Makes `Env::active()` return an `Env` until dropped, so the `Env` is
+/// released even when the call panics.
+struct ActiveEnv;
+
+impl ActiveEnv {
+    fn enter(env: &Env) -> Self {
+        ACTIVE_ENVS.with(|envs| envs.borrow_mut().push(env.clone()));
+        ActiveEnv
+    }
+}
 
-pub static mut CURRENT_CONTRACT: u8 = 0;
+impl Drop for ActiveEnv {
+    fn drop(&mut self) {
+        ACTIVE_ENVS.with(|envs| envs.borrow_mut().pop());
+    }
+}
+
+/// Makes `current_contract_address()` return another contract until
+/// dropped, restoring the previous one even when the call panics.
+struct CurrentContract<'a> {
+    env: &'a Env,
+    previous: Address,
+}
+
+impl<'a> CurrentContract<'a> {
+    fn enter(env: &'a Env, contract: Address) -> Self {
+        let previous = env.current_contract.replace(contract);
+        CurrentContract { env, previous }
+    }
+}
+
+impl Drop for CurrentContract<'_> {
+    fn drop(&mut self) {
+        self.env.current_contract.set(self.previous);
+    }
+}
+
+/// Keeps an invocation on the call stack until dropped, when the
+/// authorizations granted during it end, even when the call panics.
+struct InvocationFrame<'a> {
+    env: &'a Env,
+}
+
+impl<'a> InvocationFrame<'a> {
+    fn enter(env: &'a Env, invocation: Invocation) -> Self {
+        env.invocations.borrow_mut().push(invocation);
+        InvocationFrame { env }
+    }
+}
+
+impl Drop for InvocationFrame<'_> {
+    fn drop(&mut self) {
+        let depth = self.env.invocations.borrow().len();
+        for authorization in self.env.contract_auths.borrow_mut().iter_mut() {
+            if authorization.depth == depth {
+                authorization.active = false;
+            }
+        }
+        for entry in self.env.consumed_nonces.borrow_mut().iter_mut() {
+            if entry.depth == depth {
+                entry.active = false;
+            }
+        }
+        self.env.invocations.borrow_mut().pop();
+    }
+}
+
+/// An event published by a contract, in the order it was emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvent {
+    pub contract: Address,
+    pub topics: Val,
+    pub data: Val,
+}
+
+/// Ledger state visible to contracts through `env.ledger()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerInfo {
+    pub network_id: [u8; 32],
+    pub protocol_version: u32,
+    pub sequence_number: u32,
+    pub timestamp: u64,
+}
+
+impl Default for LedgerInfo {
+    fn default() -> Self {
+        LedgerInfo {
+            network_id: [1; 32],
+            protocol_version: u32::MAX,
+            sequence_number: 0,
+            // Current timestamp as of the time of writing this code.
+            timestamp: 1701348881,
+        }
+    }
+}
+
+/// A contract registered with [`Env::register_contract`].
+#[derive(Clone)]
+pub struct RegisteredContract {
+    pub address: Address,
+    pub functions: Rc<dyn ContractFunctionSet>,
+}
+
+impl Debug for RegisteredContract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredContract")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
 
 #[derive(Debug)]
 pub struct Env {
+    pub current_contract: Rc<Cell<Address>>,
     pub storage: Rc<RefCell<Storage>>,
+    pub event_log: Rc<RefCell<Vec<ContractEvent>>>,
+    pub ledger_info: Rc<RefCell<LedgerInfo>>,
+    pub contracts: Rc<RefCell<Vec<RegisteredContract>>>,
+    pub invocations: Rc<RefCell<Vec<Invocation>>>,
+    pub auth_entries: Rc<RefCell<Vec<AuthorizationEntry>>>,
+    pub consumed_nonces: Rc<RefCell<Vec<ConsumedNonce>>>,
+    pub contract_auths: Rc<RefCell<Vec<ContractAuthorization>>>,
+    pub mock_auths: Rc<Cell<bool>>,
+    pub adversarial_callees: Rc<Cell<bool>>,
+    pub auths: Rc<RefCell<Vec<AuthRecord>>>,
+    /// Network addresses decoded from XDR, by the `Address` standing in for
+    /// each.
+    pub addresses: Rc<RefCell<Vec<(Address, ScAddress)>>>,
 }
 
 impl Clone for Env {
     fn clone(&self) -> Self {
         Env {
+            current_contract: self.current_contract.clone(),
             storage: self.storage.clone(), // Cloning the Rc
+            event_log: self.event_log.clone(),
+            ledger_info: self.ledger_info.clone(),
+            contracts: self.contracts.clone(),
+            invocations: self.invocations.clone(),
+            auth_entries: self.auth_entries.clone(),
+            consumed_nonces: self.consumed_nonces.clone(),
+            contract_auths: self.contract_auths.clone(),
+            mock_auths: self.mock_auths.clone(),
+            adversarial_callees: self.adversarial_callees.clone(),
+            auths: self.auths.clone(),
+            addresses: self.addresses.clone(),
         }
     }
 }
@@ -35,44 +185,255 @@ impl internal::Env for Env {}
 
 impl Env {
     fn default_with_testutils() -> Env {
+        let current_contract = Rc::new(Cell::new(Address::default()));
         Env {
-            storage: Rc::new(RefCell::new(Storage::default())),
+            current_contract: current_contract.clone(),
+            storage: Rc::new(RefCell::new(Storage::new(current_contract))),
+            event_log: Rc::new(RefCell::new(Vec::new())),
+            ledger_info: Rc::new(RefCell::new(LedgerInfo::default())),
+            contracts: Rc::new(RefCell::new(Vec::new())),
+            invocations: Rc::new(RefCell::new(Vec::new())),
+            auth_entries: Rc::new(RefCell::new(Vec::new())),
+            consumed_nonces: Rc::new(RefCell::new(Vec::new())),
+            contract_auths: Rc::new(RefCell::new(Vec::new())),
+            mock_auths: Rc::new(Cell::new(false)),
+            adversarial_callees: Rc::new(Cell::new(false)),
+            auths: Rc::new(RefCell::new(Vec::new())),
+            addresses: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
-    pub fn storage(&self) -> Ref<Storage> {
+    /// Returns the `Env` of the innermost contract call running, if any.
+    pub(crate) fn active() -> Option<Env> {
+        ACTIVE_ENVS.with(|envs| envs.borrow().last().cloned())
+    }
+
+    pub fn storage(&self) -> Ref<'_, Storage> {
         self.storage.borrow()
     }
 
+    pub fn ledger_info(&self) -> LedgerInfo {
+        *self.ledger_info.borrow()
+    }
+
+    pub fn set_ledger_info(&self, info: LedgerInfo) {
+        *self.ledger_info.borrow_mut() = info;
+    }
+
     pub fn current_contract_address(&self) -> Address {
-        unsafe {
-            Address {
-                val: CURRENT_CONTRACT,
-            }
-        }
+        self.current_contract.get()
     }
 
     #[cfg(any(kani, feature = "kani"))]
-    pub fn invoke_contract<T: kani::Arbitrary>(
+    pub fn invoke_contract<T: FromValEnum + kani::Arbitrary>(
         &self,
-        _contract_address: &Address,
-        _func: &crate::Symbol,
-        _args: crate::Vec<Val>,
+        contract_address: &Address,
+        func: &crate::Symbol,
+        args: crate::Vec<Val>,
     ) -> T {
-        kani::any()
+        if !self.has_contract(contract_address) {
+            return self.call_untrusted(contract_address, func, &args);
+        }
+        self.invoke_registered(contract_address, func, &args)
     }
 
     #[cfg(not(any(kani, feature = "kani")))]
-    pub fn invoke_contract<T>(
+    pub fn invoke_contract<T: FromValEnum>(
+        &self,
+        contract_address: &Address,
+        func: &crate::Symbol,
+        args: crate::Vec<Val>,
+    ) -> T {
+        if !self.has_contract(contract_address) {
+            unimplemented!("Cross-contract calls to unregistered contracts not supported");
+        }
+        self.invoke_registered(contract_address, func, &args)
+    }
+
+    pub(crate) fn invoke_registered<T: FromValEnum>(
         &self,
-        _contract_address: &Address,
-        _func: &crate::Symbol,
-        _args: crate::Vec<Val>,
+        contract_address: &Address,
+        func: &Symbol,
+        args: &[Val],
     ) -> T {
-        unimplemented!("Cross-contract calls not supported");
+        let result = self
+            .call_contract(contract_address, func, args)
+            .unwrap_or_else(|| panic!("{} has no function {}", contract_address, func));
+        T::from_val(result).expect("invalid contract call result type")
     }
 
-    pub fn mock_all_auths(&self) {}
+    /// Calls `func` on a contract whose code isn't known, returning an
+    /// arbitrary result.
+    ///
+    /// With [`Env::enable_adversarial_callees`], the callee may also emit an
+    /// event, call back into another registered contract that isn't on the
+    /// call stack, and abort.
+    ///
+    /// An abort isn't returned to the caller as an error: the paths where the
+    /// callee aborts are pruned with `kani::assume(false)`, as if the whole
+    /// transaction had reverted. Properties of callers that recover from a
+    /// failed call are therefore not checked.
+    #[cfg(any(kani, feature = "kani"))]
+    pub fn call_untrusted<T: kani::Arbitrary>(
+        &self,
+        contract_address: &Address,
+        func: &Symbol,
+        args: &[Val],
+    ) -> T {
+        if self.adversarial_callees.get() {
+            let invocation = Invocation {
+                contract: *contract_address,
+                fn_name: *func,
+                args: crate::Vec::new_from_slice(args),
+            };
+            self.with_invocation(invocation, || self.act_as_adversary(*contract_address));
+            if kani::any() {
+                kani::assume(false);
+            }
+        }
+        kani::any()
+    }
+
+    /// Runs arbitrary side effects as `callee`. Aborting is left to
+    /// [`Env::call_untrusted`], which prunes the paths where it happens.
+    #[cfg(any(kani, feature = "kani"))]
+    fn act_as_adversary(&self, callee: Address) {
+        if kani::any() {
+            self.publish_event(callee, kani::any::<Symbol>(), kani::any::<i128>());
+        }
+        if kani::any() {
+            let targets: Vec<RegisteredContract> = {
+                let invocations = self.invocations.borrow();
+                self.contracts
+                    .borrow()
+                    .iter()
+                    .filter(|contract| {
+                        !invocations
+                            .iter()
+                            .any(|frame| frame.contract == contract.address)
+                    })
+                    .cloned()
+                    .collect()
+            };
+            let target: usize = kani::any();
+            kani::assume(target < targets.len());
+            let target = &targets[target];
+            let functions = target.functions.functions();
+            let func: usize = kani::any();
+            kani::assume(func < functions.len());
+            target
+                .functions
+                .call_arbitrary(self, &target.address, &Symbol::from(functions[func]));
+        }
+    }
+
+    /// Returns whether a contract was registered at `address`.
+    pub fn has_contract(&self, address: &Address) -> bool {
+        self.contracts
+            .borrow()
+            .iter()
+            .any(|contract| contract.address == *address)
+    }
+
+    /// Calls `func` on the contract registered at `address`, returning `None`
+    /// if there is no such contract or function.
+    pub fn call_contract(&self, address: &Address, func: &Symbol, args: &[Val]) -> Option<Val> {
+        let contract = self
+            .contracts
+            .borrow()
+            .iter()
+            .find(|contract| contract.address == *address)
+            .map(|contract| contract.functions.clone())?;
+        let invocation = Invocation {
+            contract: *address,
+            fn_name: *func,
+            args: crate::Vec::new_from_slice(args),
+        };
+        self.with_invocation(invocation, || contract.call(self, func, args))
+    }
+
+    /// Runs `f` as the given invocation: `current_contract_address()` returns
+    /// the invoked contract until `f` returns.
+    ///
+    /// A top-level invocation starts with no recorded authorizations, as
+    /// [`Env::auths`] only covers the last one.
+    ///
+    /// Panics if the contract is already on the call stack, as the host
+    /// doesn't allow contracts to be re-entered.
+    pub fn with_invocation<R>(&self, invocation: Invocation, f: impl FnOnce() -> R) -> R {
+        if self
+            .invocations
+            .borrow()
+            .iter()
+            .any(|frame| frame.contract == invocation.contract)
+        {
+            panic!("Contract re-entry is not allowed: {}", invocation.contract);
+        }
+        if self.invocations.borrow().is_empty() {
+            self.auths.borrow_mut().clear();
+        }
+        let _active = ActiveEnv::enter(self);
+        let _caller = CurrentContract::enter(self, invocation.contract);
+        let _frame = InvocationFrame::enter(self, invocation);
+        f()
+    }
+
+    /// Runs `f` as contract `id` without invoking it:
+    /// `current_contract_address()` and `storage()` refer to `id` until `f`
+    /// returns. Useful to read or seed a contract's storage from tests and
+    /// `init` blocks.
+    pub fn as_contract<T>(&self, id: &Address, f: impl FnOnce() -> T) -> T {
+        let _active = ActiveEnv::enter(self);
+        let _previous = CurrentContract::enter(self, *id);
+        f()
+    }
+
+    /// Returns the contract calls currently executing, outermost first.
+    pub fn call_stack(&self) -> Vec<Invocation> {
+        self.invocations.borrow().clone()
+    }
+
+    /// Returns the contract call currently executing, if any.
+    pub fn current_invocation(&self) -> Option<Invocation> {
+        self.invocations.borrow().last().cloned()
+    }
+
+    /// Returns the contract that invoked the current one, if it was invoked
+    /// by another contract.
+    pub fn invoker(&self) -> Option<Address> {
+        let invocations = self.invocations.borrow();
+        let depth = invocations.len();
+        if depth < 2 {
+            None
+        } else {
+            Some(invocations[depth - 2].contract)
+        }
+    }
+
+    /// Skips the `__check_auth` of custom account contracts: every
+    /// `require_auth` succeeds.
+    pub fn mock_all_auths(&self) {
+        self.mock_auths.set(true);
+    }
+
+    /// Treats contracts that weren't registered as malicious: calls into them
+    /// may emit events, call other registered contracts and abort, on top of
+    /// returning arbitrary values. Only has an effect under Kani.
+    pub fn enable_adversarial_callees(&self) {
+        self.adversarial_callees.set(true);
+    }
+
+    /// Provides the authorization entries custom account contracts check in
+    /// `__check_auth`. Nonces used by earlier entries stay consumed.
+    pub fn set_auths(&self, entries: &[AuthorizationEntry]) {
+        *self.auth_entries.borrow_mut() = entries.to_vec();
+    }
+
+    /// Returns the authorizations required through `require_auth` and
+    /// `require_auth_for_args` during the last top-level invocation.
+    pub fn auths(&self) -> Vec<AuthRecord> {
+        self.auths.borrow().clone()
+    }
 
     pub fn register_stellar_asset_contract(&self, admin: Address) -> Address {
         let contract_address = Address::new(self);
@@ -81,23 +442,114 @@ impl Env {
             "Stellar Lumens".into(),
             "XLM".into(),
             7,
-            100_000_000_000,
+            0,
             admin,
         );
         self.storage.borrow_mut().insert_token(token);
         contract_address
     }
 
-    pub fn register_contract<T>(&self, _contract_id: Option<Address>, _contract: T) -> Address {
-        let contract_address = Address::new(self);
-        unsafe {
-            CURRENT_CONTRACT = contract_address.val;
+    pub fn register_contract<T: ContractFunctionSet + 'static>(
+        &self,
+        contract_id: Option<Address>,
+        contract: T,
+    ) -> Address {
+        let contract_address = contract_id.unwrap_or_else(|| Address::new(self));
+        self.contracts.borrow_mut().push(RegisteredContract {
+            address: contract_address,
+            functions: Rc::new(contract),
+        });
+        self.current_contract.set(contract_address);
+        contract_address
+    }
+
+    /// Registers `contract` and runs its `__constructor` with
+    /// `constructor_args`, as deploying it would.
+    ///
+    /// Panics if arguments are given to a contract without a constructor.
+    pub fn register<T, A>(&self, contract: T, constructor_args: A) -> Address
+    where
+        T: ContractFunctionSet + 'static,
+        A: IntoVal<Env, crate::Vec<Val>>,
+    {
+        let has_constructor = contract.functions().contains(&CONSTRUCTOR);
+        let args = constructor_args.into_val(self);
+        if !has_constructor && !args.is_empty() {
+            panic!(
+                "constructor arguments given to a contract without {}",
+                CONSTRUCTOR
+            );
+        }
+        let contract_address = self.register_contract(None, contract);
+        if has_constructor {
+            self.call_contract(&contract_address, &Symbol::from(CONSTRUCTOR), &args);
+        }
+        contract_address
+    }
+
+    /// Registers `contract` and runs its `__constructor`, if it has one, with
+    /// arbitrary arguments.
+    #[cfg(any(kani, feature = "kani"))]
+    pub fn register_with_any_args<T: ContractFunctionSet + 'static>(&self, contract: T) -> Address {
+        let has_constructor = contract.functions().contains(&CONSTRUCTOR);
+        let contract_address = self.register_contract(None, contract);
+        if has_constructor {
+            let functions = self
+                .contracts
+                .borrow()
+                .iter()
+                .find(|contract| contract.address == contract_address)
+                .map(|contract| contract.functions.clone())
+                .unwrap();
+            functions.call_arbitrary(self, &contract_address, &Symbol::from(CONSTRUCTOR));
         }
         contract_address
     }
 
-    pub fn authorize_as_current_contract<T>(&self, _auth_entries: Vec<T>) {
-        // nop
+    /// Authorizes the calls in `auth_entries`, made deeper in the call
+    /// stack, on behalf of the current contract until its invocation
+    /// returns. Calls the contract makes directly are always authorized.
+    pub fn authorize_as_current_contract<T>(&self, auth_entries: crate::Vec<T>)
+    where
+        T: Clone + Into<AuthorizedInvocation>,
+    {
+        let contract = self.current_contract_address();
+        let depth = self.invocations.borrow().len();
+        for entry in auth_entries {
+            self.contract_auths
+                .borrow_mut()
+                .push(ContractAuthorization {
+                    contract,
+                    root_invocation: entry.into(),
+                    used: Vec::new(),
+                    active: true,
+                    depth,
+                });
+        }
+    }
+
+    /// Returns every authorization granted through
+    /// [`Env::authorize_as_current_contract`], with the calls it authorized.
+    pub fn contract_authorizations(&self) -> Vec<ContractAuthorization> {
+        self.contract_auths.borrow().clone()
+    }
+
+    /// Records an event emitted by `contract`.
+    pub fn publish_event<T, D>(&self, contract: Address, topics: T, data: D)
+    where
+        T: ToValEnum,
+        D: ToValEnum,
+    {
+        self.event_log.borrow_mut().push(ContractEvent {
+            contract,
+            topics: topics.to_val(),
+            data: data.to_val(),
+        });
+    }
+
+    /// Returns all events published so far.
+    pub fn published_events(&self) -> Vec<ContractEvent> {
+        self.event_log.borrow().clone()
     }
 }
 
@@ -1,31 +1,45 @@
 pub mod address;
-pub mod enums;
+pub mod auth;
+pub mod bytes;
+pub mod dispatch;
 pub mod env;
 pub mod num;
+mod scval;
 pub mod storage;
 pub mod string;
 pub mod symbol;
 pub mod token;
+#[cfg(any(kani, feature = "kani"))]
+pub mod token_spec;
 pub mod tuple;
 pub mod val;
 pub mod vec;
 
 pub use {
     address::Address,
-    enums::EnumType,
-    env::{Env, IntoVal, TryFromVal, TryIntoVal},
-    num::{Duration, Timepoint},
+    auth::{
+        AuthRecord, AuthorizationEntry, AuthorizedFunction, AuthorizedInvocation,
+        ContractAuthorization,
+    },
+    bytes::{Bytes, BytesN},
+    env::{ContractEvent, Env, IntoVal, LedgerInfo, TryFromVal, TryIntoVal},
+    num::{Duration, Timepoint, I256, U256},
+    scval::{from_scval, to_scval},
     storage::Storage,
     string::String,
     symbol::Symbol,
-    token::{AdminClient, MockToken, TokenClient, TokenInterface},
+    token::{
+        AdminClient, AllowanceValue, MockToken, StellarAssetClient, StellarAssetInterface,
+        TokenClient, TokenInterface,
+    },
     val::{BytesObject, ConversionError, FromValEnum, ToValEnum, Val},
     vec::Vec,
 };
 
 #[macro_export]
 macro_rules! symbol_short {
-    ($input:expr) => {
-        soroban_sdk::Symbol::new_from_str($input)
-    };
+    ($input:expr) => {{
+        const SYMBOL: soroban_sdk::Symbol = soroban_sdk::Symbol::short($input);
+        SYMBOL
+    }};
 }
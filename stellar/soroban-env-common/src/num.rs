@@ -1,6 +1,6 @@
-use crate::{FromValEnum, ToValEnum, Val};
+use crate::{Bytes, Env, FromValEnum, ToValEnum, Val};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct Timepoint(u64);
 
 impl ToValEnum for Timepoint {
@@ -25,6 +25,23 @@ impl From<u64> for Timepoint {
     }
 }
 
+impl Timepoint {
+    /// Creates a timepoint from seconds since the Unix epoch.
+    pub fn from_unix(_env: &Env, seconds: u64) -> Timepoint {
+        Timepoint(seconds)
+    }
+
+    /// Returns the seconds since the Unix epoch.
+    pub fn to_unix(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the timepoint `duration` later, or `None` on overflow.
+    pub fn checked_add(&self, duration: &Duration) -> Option<Timepoint> {
+        self.0.checked_add(duration.0).map(Timepoint)
+    }
+}
+
 #[cfg(any(kani, feature = "kani"))]
 impl kani::Arbitrary for Timepoint {
     fn any() -> Self {
@@ -32,7 +49,7 @@ impl kani::Arbitrary for Timepoint {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct Duration(u64);
 
 impl ToValEnum for Duration {
@@ -51,6 +68,22 @@ impl FromValEnum for Duration {
     }
 }
 
+impl From<u64> for Duration {
+    fn from(i: u64) -> Self {
+        Duration(i)
+    }
+}
+
+impl Duration {
+    pub fn from_seconds(_env: &Env, seconds: u64) -> Duration {
+        Duration(seconds)
+    }
+
+    pub fn to_seconds(&self) -> u64 {
+        self.0
+    }
+}
+
 #[cfg(any(kani, feature = "kani"))]
 impl kani::Arbitrary for Duration {
     fn any() -> Self {
@@ -159,3 +192,606 @@ impl FromValEnum for u128 {
         }
     }
 }
+
+const LOW_64: u128 = u64::MAX as u128;
+
+// Multiplies two u128s into a 256-bit (hi, lo) product.
+const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a & LOW_64);
+    let (b1, b0) = (b >> 64, b & LOW_64);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 64) + (p01 & LOW_64) + (p10 & LOW_64);
+    let lo = (p00 & LOW_64) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Unsigned 256-bit integer.
+///
+/// Arithmetic panics on overflow, division by zero and shifts of 256 bits or
+/// more, as the host does. Dividing values that don't fit in a `u128` loops
+/// over every bit, so harnesses doing so need an unwind bound above 256.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+#[allow(clippy::should_implement_trait)]
+impl U256 {
+    const ZERO: U256 = U256 { hi: 0, lo: 0 };
+    const ONE: U256 = U256 { hi: 0, lo: 1 };
+
+    pub fn from_u32(_env: &Env, u: u32) -> Self {
+        U256 {
+            hi: 0,
+            lo: u as u128,
+        }
+    }
+
+    pub fn from_u128(_env: &Env, u: u128) -> Self {
+        U256 { hi: 0, lo: u }
+    }
+
+    pub fn from_parts(_env: &Env, hi_hi: u64, hi_lo: u64, lo_hi: u64, lo_lo: u64) -> Self {
+        U256 {
+            hi: ((hi_hi as u128) << 64) | hi_lo as u128,
+            lo: ((lo_hi as u128) << 64) | lo_lo as u128,
+        }
+    }
+
+    /// Creates a `U256` from 32 big-endian bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If `bytes` isn't 32 bytes long.
+    #[track_caller]
+    pub fn from_be_bytes(_env: &Env, bytes: &Bytes) -> Self {
+        let (hi, lo) = split_be_bytes(bytes);
+        U256 {
+            hi: u128::from_be_bytes(hi),
+            lo: u128::from_be_bytes(lo),
+        }
+    }
+
+    pub fn env(&self) -> Env {
+        Env::default()
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.hi == 0 {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_be_bytes(&self) -> Bytes {
+        join_be_bytes(self.hi.to_be_bytes(), self.lo.to_be_bytes())
+    }
+
+    #[track_caller]
+    pub fn add(&self, other: &U256) -> U256 {
+        match self.overflowing_add(other) {
+            (res, false) => res,
+            (_, true) => panic!("attempt to add with overflow"),
+        }
+    }
+
+    #[track_caller]
+    pub fn sub(&self, other: &U256) -> U256 {
+        match self.overflowing_sub(other) {
+            (res, false) => res,
+            (_, true) => panic!("attempt to subtract with overflow"),
+        }
+    }
+
+    #[track_caller]
+    pub fn mul(&self, other: &U256) -> U256 {
+        self.checked_mul(other)
+            .expect("attempt to multiply with overflow")
+    }
+
+    #[track_caller]
+    pub fn div(&self, other: &U256) -> U256 {
+        self.div_rem(other).0
+    }
+
+    #[track_caller]
+    pub fn rem_euclid(&self, other: &U256) -> U256 {
+        self.div_rem(other).1
+    }
+
+    #[track_caller]
+    pub fn pow(&self, pow: u32) -> U256 {
+        if pow == 0 {
+            return U256::ONE;
+        }
+        let mut base = *self;
+        let mut acc = U256::ONE;
+        let mut pow = pow;
+        while pow > 1 {
+            if pow & 1 == 1 {
+                acc = acc.mul(&base);
+            }
+            pow /= 2;
+            base = base.mul(&base);
+        }
+        acc.mul(&base)
+    }
+
+    #[track_caller]
+    pub fn shl(&self, bits: u32) -> U256 {
+        if bits >= 256 {
+            panic!("attempt to shift left with overflow");
+        }
+        self.shl_bits(bits)
+    }
+
+    #[track_caller]
+    pub fn shr(&self, bits: u32) -> U256 {
+        if bits >= 256 {
+            panic!("attempt to shift right with overflow");
+        }
+        self.shr_bits(bits)
+    }
+
+    fn overflowing_add(&self, other: &U256) -> (U256, bool) {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let (hi, overflow) = self.hi.overflowing_add(other.hi);
+        let (hi, carry_overflow) = hi.overflowing_add(carry as u128);
+        (U256 { hi, lo }, overflow || carry_overflow)
+    }
+
+    fn overflowing_sub(&self, other: &U256) -> (U256, bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let (hi, overflow) = self.hi.overflowing_sub(other.hi);
+        let (hi, borrow_overflow) = hi.overflowing_sub(borrow as u128);
+        (U256 { hi, lo }, overflow || borrow_overflow)
+    }
+
+    fn checked_mul(&self, other: &U256) -> Option<U256> {
+        if self.hi != 0 && other.hi != 0 {
+            return None;
+        }
+        let (hi, lo) = widening_mul(self.lo, other.lo);
+        let cross = self
+            .hi
+            .checked_mul(other.lo)?
+            .checked_add(self.lo.checked_mul(other.hi)?)?;
+        Some(U256 {
+            hi: hi.checked_add(cross)?,
+            lo,
+        })
+    }
+
+    #[track_caller]
+    fn div_rem(&self, other: &U256) -> (U256, U256) {
+        if *other == U256::ZERO {
+            panic!("attempt to divide by zero");
+        }
+        if self.hi == 0 && other.hi == 0 {
+            return (
+                U256::from_lo(self.lo / other.lo),
+                U256::from_lo(self.lo % other.lo),
+            );
+        }
+        let mut quotient = U256::ZERO;
+        let mut rem = U256::ZERO;
+        for i in (0..256).rev() {
+            let carry = rem.hi >> 127 == 1;
+            rem = rem.shl_bits(1);
+            rem.lo |= self.bit(i);
+            if carry || rem >= *other {
+                rem = rem.overflowing_sub(other).0;
+                quotient = quotient.overflowing_add(&U256::ONE.shl_bits(i)).0;
+            }
+        }
+        (quotient, rem)
+    }
+
+    fn from_lo(lo: u128) -> U256 {
+        U256 { hi: 0, lo }
+    }
+
+    fn bit(&self, i: u32) -> u128 {
+        if i >= 128 {
+            (self.hi >> (i - 128)) & 1
+        } else {
+            (self.lo >> i) & 1
+        }
+    }
+
+    fn not(&self) -> U256 {
+        U256 {
+            hi: !self.hi,
+            lo: !self.lo,
+        }
+    }
+
+    fn shl_bits(&self, bits: u32) -> U256 {
+        match bits {
+            0 => *self,
+            1..=127 => U256 {
+                hi: (self.hi << bits) | (self.lo >> (128 - bits)),
+                lo: self.lo << bits,
+            },
+            _ => U256 {
+                hi: self.lo << (bits - 128),
+                lo: 0,
+            },
+        }
+    }
+
+    fn shr_bits(&self, bits: u32) -> U256 {
+        match bits {
+            0 => *self,
+            1..=127 => U256 {
+                hi: self.hi >> bits,
+                lo: (self.lo >> bits) | (self.hi << (128 - bits)),
+            },
+            _ => U256 {
+                hi: 0,
+                lo: self.hi >> (bits - 128),
+            },
+        }
+    }
+}
+
+impl ToValEnum for U256 {
+    fn to_val(&self) -> Val {
+        Val::U256Val(*self)
+    }
+}
+
+impl FromValEnum for U256 {
+    fn from_val(val: Val) -> Option<U256> {
+        if let Val::U256Val(u) = val {
+            Some(u)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl kani::Arbitrary for U256 {
+    fn any() -> Self {
+        U256 {
+            hi: kani::any(),
+            lo: kani::any(),
+        }
+    }
+}
+
+/// Signed 256-bit integer, in two's complement.
+///
+/// Overflow, division and shift behaviour is the same as for [`U256`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct I256 {
+    hi: i128,
+    lo: u128,
+}
+
+#[allow(clippy::should_implement_trait)]
+impl I256 {
+    const MIN: I256 = I256 {
+        hi: i128::MIN,
+        lo: 0,
+    };
+
+    pub fn from_i32(_env: &Env, i: i32) -> Self {
+        I256::from_i128_bits(i as i128)
+    }
+
+    pub fn from_i128(_env: &Env, i: i128) -> Self {
+        I256::from_i128_bits(i)
+    }
+
+    pub fn from_parts(_env: &Env, hi_hi: i64, hi_lo: u64, lo_hi: u64, lo_lo: u64) -> Self {
+        I256 {
+            hi: ((hi_hi as i128) << 64) | hi_lo as i128,
+            lo: ((lo_hi as u128) << 64) | lo_lo as u128,
+        }
+    }
+
+    /// Creates an `I256` from 32 big-endian bytes.
+    ///
+    /// ### Panics
+    ///
+    /// If `bytes` isn't 32 bytes long.
+    #[track_caller]
+    pub fn from_be_bytes(_env: &Env, bytes: &Bytes) -> Self {
+        let (hi, lo) = split_be_bytes(bytes);
+        I256 {
+            hi: i128::from_be_bytes(hi),
+            lo: u128::from_be_bytes(lo),
+        }
+    }
+
+    pub fn env(&self) -> Env {
+        Env::default()
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        let lo = self.lo as i128;
+        if self.hi == lo >> 127 {
+            Some(lo)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_be_bytes(&self) -> Bytes {
+        join_be_bytes(self.hi.to_be_bytes(), self.lo.to_be_bytes())
+    }
+
+    #[track_caller]
+    pub fn add(&self, other: &I256) -> I256 {
+        let res = I256::from_bits(self.bits().overflowing_add(&other.bits()).0);
+        if self.is_negative() == other.is_negative() && res.is_negative() != self.is_negative() {
+            panic!("attempt to add with overflow");
+        }
+        res
+    }
+
+    #[track_caller]
+    pub fn sub(&self, other: &I256) -> I256 {
+        let res = I256::from_bits(self.bits().overflowing_sub(&other.bits()).0);
+        if self.is_negative() != other.is_negative() && res.is_negative() != self.is_negative() {
+            panic!("attempt to subtract with overflow");
+        }
+        res
+    }
+
+    #[track_caller]
+    pub fn mul(&self, other: &I256) -> I256 {
+        self.magnitude()
+            .checked_mul(&other.magnitude())
+            .and_then(|m| I256::from_magnitude(self.is_negative() != other.is_negative(), m))
+            .expect("attempt to multiply with overflow")
+    }
+
+    #[track_caller]
+    pub fn div(&self, other: &I256) -> I256 {
+        let (quotient, _) = self.magnitude().div_rem(&other.magnitude());
+        I256::from_magnitude(self.is_negative() != other.is_negative(), quotient)
+            .expect("attempt to divide with overflow")
+    }
+
+    #[track_caller]
+    pub fn rem_euclid(&self, other: &I256) -> I256 {
+        let (_, mut rem) = self.magnitude().div_rem(&other.magnitude());
+        if *other == I256::from_i128_bits(-1) && *self == I256::MIN {
+            panic!("attempt to calculate the remainder with overflow");
+        }
+        if self.is_negative() && rem != U256::ZERO {
+            rem = other.magnitude().overflowing_sub(&rem).0;
+        }
+        I256::from_bits(rem)
+    }
+
+    #[track_caller]
+    pub fn pow(&self, pow: u32) -> I256 {
+        if pow == 0 {
+            return I256::from_i128_bits(1);
+        }
+        let mut base = *self;
+        let mut acc = I256::from_i128_bits(1);
+        let mut pow = pow;
+        while pow > 1 {
+            if pow & 1 == 1 {
+                acc = acc.mul(&base);
+            }
+            pow /= 2;
+            base = base.mul(&base);
+        }
+        acc.mul(&base)
+    }
+
+    #[track_caller]
+    pub fn shl(&self, bits: u32) -> I256 {
+        I256::from_bits(self.bits().shl(bits))
+    }
+
+    #[track_caller]
+    pub fn shr(&self, bits: u32) -> I256 {
+        let shifted = self.bits().shr(bits);
+        if self.is_negative() {
+            // Shift ones in from the left.
+            let fill = U256::ZERO.not().shr_bits(bits).not();
+            I256::from_bits(U256 {
+                hi: shifted.hi | fill.hi,
+                lo: shifted.lo | fill.lo,
+            })
+        } else {
+            I256::from_bits(shifted)
+        }
+    }
+
+    fn from_i128_bits(i: i128) -> I256 {
+        I256 {
+            hi: i >> 127,
+            lo: i as u128,
+        }
+    }
+
+    fn is_negative(&self) -> bool {
+        self.hi < 0
+    }
+
+    fn bits(&self) -> U256 {
+        U256 {
+            hi: self.hi as u128,
+            lo: self.lo,
+        }
+    }
+
+    fn from_bits(bits: U256) -> I256 {
+        I256 {
+            hi: bits.hi as i128,
+            lo: bits.lo,
+        }
+    }
+
+    // The absolute value, which fits in a U256 even for the minimum.
+    fn magnitude(&self) -> U256 {
+        if self.is_negative() {
+            U256::ZERO.overflowing_sub(&self.bits()).0
+        } else {
+            self.bits()
+        }
+    }
+
+    fn from_magnitude(negative: bool, magnitude: U256) -> Option<I256> {
+        let min_magnitude = I256::MIN.bits();
+        if negative && magnitude != U256::ZERO {
+            if magnitude > min_magnitude {
+                return None;
+            }
+            Some(I256::from_bits(U256::ZERO.overflowing_sub(&magnitude).0))
+        } else if magnitude >= min_magnitude {
+            None
+        } else {
+            Some(I256::from_bits(magnitude))
+        }
+    }
+}
+
+impl ToValEnum for I256 {
+    fn to_val(&self) -> Val {
+        Val::I256Val(*self)
+    }
+}
+
+impl FromValEnum for I256 {
+    fn from_val(val: Val) -> Option<I256> {
+        if let Val::I256Val(i) = val {
+            Some(i)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(any(kani, feature = "kani"))]
+impl kani::Arbitrary for I256 {
+    fn any() -> Self {
+        I256 {
+            hi: kani::any(),
+            lo: kani::any(),
+        }
+    }
+}
+
+#[track_caller]
+fn split_be_bytes(bytes: &Bytes) -> ([u8; 16], [u8; 16]) {
+    let bytes = bytes.as_slice();
+    if bytes.len() != 32 {
+        panic!("expected 32 bytes, got {}", bytes.len());
+    }
+    (
+        bytes[..16].try_into().unwrap(),
+        bytes[16..].try_into().unwrap(),
+    )
+}
+
+fn join_be_bytes(hi: [u8; 16], lo: [u8; 16]) -> Bytes {
+    let mut bytes = [0; 32];
+    bytes[..16].copy_from_slice(&hi);
+    bytes[16..].copy_from_slice(&lo);
+    Bytes::from_array(&Env::default(), &bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_timepoint() {
+        let env = Env::default();
+        let start = Timepoint::from_unix(&env, 1_000);
+        let day = Duration::from_seconds(&env, 86_400);
+        assert_eq!(start.checked_add(&day).unwrap().to_unix(), 87_400);
+        assert_eq!(day.to_seconds(), 86_400);
+        assert_eq!(Timepoint::from(u64::MAX).checked_add(&day), None);
+    }
+
+    #[test]
+    fn test_u256_arithmetic() {
+        let env = Env::default();
+        let max = U256::from_u128(&env, u128::MAX);
+        let sum = max.add(&U256::from_u32(&env, 1));
+        assert_eq!(sum, U256::from_parts(&env, 0, 1, 0, 0));
+        assert_eq!(sum.to_u128(), None);
+        assert_eq!(sum.sub(&max).to_u128(), Some(1));
+
+        let square = max.mul(&max);
+        assert_eq!(square, U256::from_parts(&env, u64::MAX, u64::MAX - 1, 0, 1));
+        assert_eq!(square.div(&max), max);
+        assert_eq!(square.add(&max).rem_euclid(&max).to_u128(), Some(0));
+        assert_eq!(
+            U256::from_u32(&env, 2).pow(255),
+            U256::from_u32(&env, 1).shl(255)
+        );
+        assert_eq!(sum.shr(128).to_u128(), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_u256_overflow() {
+        let env = Env::default();
+        U256::from_u32(&env, 2).pow(256);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_u256_div_by_zero() {
+        let env = Env::default();
+        U256::from_u32(&env, 1).div(&U256::from_u32(&env, 0));
+    }
+
+    #[test]
+    fn test_i256_arithmetic() {
+        let env = Env::default();
+        let a = I256::from_i128(&env, -7);
+        let b = I256::from_i128(&env, 2);
+        assert_eq!(a.add(&b).to_i128(), Some(-5));
+        assert_eq!(a.sub(&b).to_i128(), Some(-9));
+        assert_eq!(a.mul(&b).to_i128(), Some(-14));
+        assert_eq!(a.div(&b).to_i128(), Some(-3));
+        assert_eq!(a.rem_euclid(&b).to_i128(), Some(1));
+        assert_eq!(a.pow(3).to_i128(), Some(-343));
+        assert_eq!(a.shr(1).to_i128(), Some(-4));
+        assert_eq!(a.shl(1).to_i128(), Some(-14));
+
+        let min = I256::from_parts(&env, i64::MIN, 0, 0, 0);
+        assert_eq!(min.to_i128(), None);
+        assert_eq!(min.div(&b).mul(&b), min);
+        assert_eq!(I256::from_i128(&env, i128::MIN).to_i128(), Some(i128::MIN));
+        assert!(min < a && a < b);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_i256_div_overflow() {
+        let env = Env::default();
+        let min = I256::from_parts(&env, i64::MIN, 0, 0, 0);
+        min.div(&I256::from_i32(&env, -1));
+    }
+
+    #[test]
+    fn test_256_val_and_bytes() {
+        let env = Env::default();
+        let u = U256::from_parts(&env, 1, 2, 3, 4);
+        assert_eq!(U256::from_val(u.to_val()), Some(u));
+        assert_eq!(U256::from_be_bytes(&env, &u.to_be_bytes()), u);
+        let i = I256::from_i32(&env, -1);
+        assert_eq!(I256::from_val(i.to_val()), Some(i));
+        assert_eq!(i.to_be_bytes().as_slice(), [0xff; 32]);
+        assert_eq!(I256::from_be_bytes(&env, &i.to_be_bytes()), i);
+    }
+}
@@ -0,0 +1,243 @@
+//! Conversions between [`Val`] and the network's `ScVal`.
+//!
+//! Contract types map as the host maps them: structs with named fields are
+//! `ScMap`s keyed by field name, tuple structs and tuples are `ScVec`s, and
+//! enum variants are `ScVec`s headed by the variant name.
+//!
+//! `Address` is a small id here. An address decoded from a network
+//! `ScAddress` gets a fresh id, remembered by the [`Env`] it was decoded with
+//! so that it encodes back to the same `ScAddress`. Other addresses are
+//! encoded big-endian in the last four bytes of an otherwise zeroed contract
+//! hash. `usize` has no network representation and fails to convert.
+
+use std::rc::Rc;
+
+use stellar_xdr::curr::{
+    self as xdr, Hash, Int128Parts, Int256Parts, ScAddress, ScBytes, ScMap, ScMapEntry, ScString,
+    ScSymbol, ScVal, ScVec, UInt128Parts, UInt256Parts,
+};
+
+use crate::{
+    Address, Bytes, ConversionError, Duration, Env, String, Symbol, Timepoint, ToValEnum, Val, Vec,
+    I256, U256,
+};
+
+/// Converts `val` to an `ScVal`, encoding addresses decoded with `env` as
+/// the `ScAddress` they were decoded from.
+pub fn to_scval(env: &Env, val: &Val) -> Result<ScVal, ConversionError> {
+    let scval = match val {
+        Val::BoolVal(b) => ScVal::Bool(*b),
+        Val::Void => ScVal::Void,
+        Val::U32Val(u) => ScVal::U32(*u),
+        Val::I32Val(i) => ScVal::I32(*i),
+        Val::U64Val(u) => ScVal::U64(*u),
+        Val::I64Val(i) => ScVal::I64(*i),
+        Val::TimepointVal(t) => ScVal::Timepoint(xdr::TimePoint(t.to_unix())),
+        Val::DurationVal(d) => ScVal::Duration(xdr::Duration(d.to_seconds())),
+        Val::U128(u) => ScVal::U128(UInt128Parts {
+            hi: (*u >> 64) as u64,
+            lo: *u as u64,
+        }),
+        Val::I128(i) => ScVal::I128(Int128Parts {
+            hi: (*i >> 64) as i64,
+            lo: *i as u64,
+        }),
+        Val::U256Val(u) => {
+            let [hi_hi, hi_lo, lo_hi, lo_lo] = be_words(&u.to_be_bytes());
+            ScVal::U256(UInt256Parts {
+                hi_hi,
+                hi_lo,
+                lo_hi,
+                lo_lo,
+            })
+        }
+        Val::I256Val(i) => {
+            let [hi_hi, hi_lo, lo_hi, lo_lo] = be_words(&i.to_be_bytes());
+            ScVal::I256(Int256Parts {
+                hi_hi: hi_hi as i64,
+                hi_lo,
+                lo_hi,
+                lo_lo,
+            })
+        }
+        Val::BytesNVal(b) => ScVal::Bytes(ScBytes(
+            b.as_slice().try_into().map_err(|_| ConversionError)?,
+        )),
+        Val::String(s) => ScVal::String(ScString(
+            s.as_bytes().try_into().map_err(|_| ConversionError)?,
+        )),
+        Val::SymbolVal(s) => ScVal::Symbol(ScSymbol(
+            s.as_bytes().try_into().map_err(|_| ConversionError)?,
+        )),
+        Val::VecVal(items) => {
+            let items = items
+                .as_slice()
+                .iter()
+                .map(|item| to_scval(env, item))
+                .collect::<Result<std::vec::Vec<_>, _>>()?;
+            ScVal::Vec(Some(ScVec(items.try_into().map_err(|_| ConversionError)?)))
+        }
+        Val::MapVal(entries) => {
+            let entries = entries
+                .iter()
+                .map(|(key, val)| {
+                    Ok(ScMapEntry {
+                        key: to_scval(env, key)?,
+                        val: to_scval(env, val)?,
+                    })
+                })
+                .collect::<Result<std::vec::Vec<_>, _>>()?;
+            ScVal::Map(Some(ScMap(
+                entries.try_into().map_err(|_| ConversionError)?,
+            )))
+        }
+        Val::AddressObj(id) => {
+            let known = env
+                .addresses
+                .borrow()
+                .iter()
+                .find_map(|(address, sc_address)| {
+                    (address.val as u32 == *id).then(|| sc_address.clone())
+                });
+            ScVal::Address(known.unwrap_or_else(|| {
+                let mut hash = [0; 32];
+                hash[28..].copy_from_slice(&id.to_be_bytes());
+                ScAddress::Contract(Hash(hash))
+            }))
+        }
+        Val::USizeVal(_) => return Err(ConversionError),
+    };
+    Ok(scval)
+}
+
+/// Converts `scval` to a `Val`, giving each `ScAddress` that isn't an
+/// encoded id an `Address` remembered by `env`.
+pub fn from_scval(env: &Env, scval: &ScVal) -> Result<Val, ConversionError> {
+    let val = match scval {
+        ScVal::Bool(b) => Val::BoolVal(*b),
+        ScVal::Void => Val::Void,
+        ScVal::U32(u) => Val::U32Val(*u),
+        ScVal::I32(i) => Val::I32Val(*i),
+        ScVal::U64(u) => Val::U64Val(*u),
+        ScVal::I64(i) => Val::I64Val(*i),
+        ScVal::Timepoint(t) => Timepoint::from_unix(env, t.0).to_val(),
+        ScVal::Duration(d) => Duration::from_seconds(env, d.0).to_val(),
+        ScVal::U128(parts) => Val::U128(((parts.hi as u128) << 64) | parts.lo as u128),
+        ScVal::I128(parts) => Val::I128(((parts.hi as i128) << 64) | parts.lo as i128),
+        ScVal::U256(parts) => Val::U256Val(U256::from_parts(
+            env,
+            parts.hi_hi,
+            parts.hi_lo,
+            parts.lo_hi,
+            parts.lo_lo,
+        )),
+        ScVal::I256(parts) => Val::I256Val(I256::from_parts(
+            env,
+            parts.hi_hi,
+            parts.hi_lo,
+            parts.lo_hi,
+            parts.lo_lo,
+        )),
+        ScVal::Bytes(b) => Bytes(Vec::from_slice_unbounded(b.as_slice())).to_val(),
+        ScVal::String(s) => String::from_bytes(env, s.as_slice()).to_val(),
+        ScVal::Symbol(s) => {
+            Val::SymbolVal(Symbol::try_from_bytes(s.as_slice()).map_err(|_| ConversionError)?)
+        }
+        ScVal::Vec(Some(items)) => Val::VecVal(
+            items
+                .iter()
+                .map(|item| from_scval(env, item).map(Rc::new))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        ScVal::Map(Some(entries)) => Val::MapVal(
+            entries
+                .iter()
+                .map(|entry| Ok((from_scval(env, &entry.key)?, from_scval(env, &entry.val)?)))
+                .collect::<Result<std::vec::Vec<_>, _>>()?,
+        ),
+        ScVal::Address(ScAddress::Contract(Hash(hash))) if hash[..28] == [0; 28] => {
+            Val::AddressObj(u32::from_be_bytes(hash[28..].try_into().unwrap()))
+        }
+        ScVal::Address(sc_address) => {
+            let known = env
+                .addresses
+                .borrow()
+                .iter()
+                .find_map(|(address, known)| (known == sc_address).then_some(*address));
+            known
+                .unwrap_or_else(|| {
+                    let address = Address::new(env);
+                    env.addresses
+                        .borrow_mut()
+                        .push((address, sc_address.clone()));
+                    address
+                })
+                .to_val()
+        }
+        _ => return Err(ConversionError),
+    };
+    Ok(val)
+}
+
+fn be_words(bytes: &Bytes) -> [u64; 4] {
+    let bytes = bytes.as_slice();
+    core::array::from_fn(|i| u64::from_be_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use stellar_xdr::curr::{Hash, ScAddress, ScVal, ScVec};
+
+    use super::{from_scval, to_scval};
+    use crate::{Address, Env, FromValEnum, Symbol, ToValEnum, Val, Vec, U256};
+
+    fn roundtrip(env: &Env, val: Val) {
+        let scval = to_scval(env, &val).unwrap();
+        assert_eq!(from_scval(env, &scval), Ok(val));
+    }
+
+    #[test]
+    fn test_scval_roundtrip() {
+        let env = Env::default();
+        roundtrip(&env, Val::Void);
+        roundtrip(&env, Val::I128(-1));
+        roundtrip(&env, U256::from_parts(&env, 1, 2, 3, 4).to_val());
+        roundtrip(&env, Symbol::from("transfer").to_val());
+        roundtrip(&env, Address::generate(&env).to_val());
+        roundtrip(
+            &env,
+            Val::MapVal(std::vec![(
+                Symbol::from("count").to_val(),
+                Val::VecVal(Vec::from([Rc::new(Val::U32Val(1))])),
+            )]),
+        );
+    }
+
+    #[test]
+    fn test_scval_usize_unsupported() {
+        let env = Env::default();
+        assert!(to_scval(&env, &Val::USizeVal(1)).is_err());
+        assert!(from_scval(&env, &ScVal::Vec(None::<ScVec>)).is_err());
+    }
+
+    #[test]
+    fn test_scval_network_address() {
+        let env = Env::default();
+        let contract = ScVal::Address(ScAddress::Contract(Hash([9; 32])));
+        let val = from_scval(&env, &contract).unwrap();
+        assert_eq!(from_scval(&env, &contract), Ok(val.clone()));
+        assert_eq!(to_scval(&env, &val), Ok(contract.clone()));
+        assert_ne!(to_scval(&Env::default(), &val), Ok(contract));
+    }
+
+    #[test]
+    fn test_scval_address_id_out_of_range() {
+        let env = Env::default();
+        let mut hash = [0; 32];
+        hash[28..].copy_from_slice(&300u32.to_be_bytes());
+        let val = from_scval(&env, &ScVal::Address(ScAddress::Contract(Hash(hash)))).unwrap();
+        assert_eq!(Address::from_val(val), None);
+    }
+}
@@ -1,15 +1,25 @@
-use std::cell::{Ref, RefCell};
+use std::cell::{Cell, RefCell};
 use std::fmt::Debug;
 use std::rc::Rc;
 
 use crate::{token::MockToken, Address, FromValEnum, ToValEnum, Val, Vec};
 
+/// The storage of every contract in an `Env`. Instance, temporary and
+/// persistent storage are resolved against the current contract.
 #[derive(Clone, Default)]
 pub struct Storage {
     tokens: Vec<MockToken>,
-    instance: Rc<RefCell<InstanceStorage>>,
-    temporary: Rc<RefCell<TemporaryStorage>>,
-    persistent: Rc<RefCell<PersistentStorage>>,
+    current_contract: Rc<Cell<Address>>,
+    contracts: Rc<RefCell<std::vec::Vec<ContractStorage>>>,
+}
+
+/// The storage of a single contract.
+#[derive(Clone, Default, Debug)]
+struct ContractStorage {
+    address: Address,
+    instance: InstanceStorage,
+    temporary: TemporaryStorage,
+    persistent: PersistentStorage,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -50,6 +60,7 @@ impl PersistentStorage {
         let exists = self
             .storage
             .borrow_mut()
+            .as_slice()
             .iter()
             .position(|(k, _)| *k == key.to_val());
 
@@ -69,6 +80,7 @@ impl PersistentStorage {
     {
         self.storage
             .borrow()
+            .as_slice()
             .iter()
             .any(|(k, _)| *k == key.to_val())
     }
@@ -99,6 +111,7 @@ impl TemporaryStorage {
         let exists = self
             .storage
             .borrow_mut()
+            .as_slice()
             .iter()
             .position(|(k, _)| *k == key.to_val());
 
@@ -118,6 +131,7 @@ impl TemporaryStorage {
     {
         self.storage
             .borrow()
+            .as_slice()
             .iter()
             .any(|(k, _)| *k == key.to_val())
     }
@@ -148,6 +162,7 @@ impl InstanceStorage {
         let exists = self
             .storage
             .borrow_mut()
+            .as_slice()
             .iter()
             .position(|(k, _)| *k == key.to_val());
 
@@ -167,6 +182,7 @@ impl InstanceStorage {
     {
         self.storage
             .borrow()
+            .as_slice()
             .iter()
             .any(|(k, _)| *k == key.to_val())
     }
@@ -178,28 +194,54 @@ impl Debug for Storage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Storage")
             .field("tokens", &self.tokens)
-            .field("instance", &self.instance)
-            .field("temporary", &self.temporary)
-            .field("persistent", &self.persistent)
+            .field("current_contract", &self.current_contract.get())
+            .field("contracts", &self.contracts.borrow())
             .finish()
     }
 }
 
 impl Storage {
+    /// Creates storage that follows the contract in `current_contract`.
+    pub(crate) fn new(current_contract: Rc<Cell<Address>>) -> Self {
+        Storage {
+            current_contract,
+            ..Storage::default()
+        }
+    }
+
     pub fn get_token(&self, address: &Address) -> Option<MockToken> {
-        self.tokens.iter().find(|t| t.address == *address).cloned()
+        self.tokens
+            .as_slice()
+            .iter()
+            .find(|t| t.address == *address)
+            .cloned()
+    }
+
+    pub fn instance(&self) -> InstanceStorage {
+        self.current().instance
     }
 
-    pub fn instance(&self) -> Ref<InstanceStorage> {
-        self.instance.borrow()
+    pub fn temporary(&self) -> TemporaryStorage {
+        self.current().temporary
     }
 
-    pub fn temporary(&self) -> Ref<TemporaryStorage> {
-        self.temporary.borrow()
+    pub fn persistent(&self) -> PersistentStorage {
+        self.current().persistent
     }
 
-    pub fn persistent(&self) -> Ref<PersistentStorage> {
-        self.persistent.borrow()
+    // Returns the storage of the current contract, creating it on first use.
+    fn current(&self) -> ContractStorage {
+        let address = self.current_contract.get();
+        let mut contracts = self.contracts.borrow_mut();
+        if let Some(storage) = contracts.iter().find(|storage| storage.address == address) {
+            return storage.clone();
+        }
+        let storage = ContractStorage {
+            address,
+            ..ContractStorage::default()
+        };
+        contracts.push(storage.clone());
+        storage
     }
 
     pub fn insert_token(&mut self, token: MockToken) {
@@ -246,4 +288,22 @@ mod test {
         assert!(instance.has(&symb));
         assert!(!instance.has(&symb2));
     }
+
+    #[test]
+    fn test_storage_per_contract() {
+        let current_contract = Rc::new(Cell::new(Address { val: 1 }));
+        let storage = Storage::new(current_contract.clone());
+        let symb = Symbol::from("test");
+        storage.instance().set(&symb, &10);
+        storage.persistent().set(&symb, &11);
+
+        current_contract.set(Address { val: 2 });
+        assert!(!storage.instance().has(&symb));
+        assert!(!storage.persistent().has(&symb));
+        storage.instance().set(&symb, &20);
+
+        current_contract.set(Address { val: 1 });
+        assert_eq!(storage.instance().get::<Symbol, i32>(&symb), Some(10));
+        assert_eq!(storage.persistent().get::<Symbol, i32>(&symb), Some(11));
+    }
 }
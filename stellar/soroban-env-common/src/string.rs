@@ -1,9 +1,16 @@
 use std::fmt;
 
-use crate::{FromValEnum, ToValEnum, Val};
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default)]
-pub struct String(pub [u8; 10]);
+use crate::{Bytes, Env, FromValEnum, ToValEnum, Val, Vec};
+
+/// A string of bytes, usually UTF-8.
+///
+/// Like `Vec`, it holds at most [`crate::vec::capacity`] bytes. Strings
+/// compare byte-wise, shorter strings first on a common prefix, like host
+/// strings do.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct String {
+    data: Vec<u8>,
+}
 
 impl fmt::Display for String {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13,56 +20,92 @@ impl fmt::Display for String {
 }
 
 impl String {
-    pub fn as_str(&self) -> &str {
-        // Convert the internal byte array to a string slice
-        // Find the first null byte (0x00) in the byte array, if any.
-        let null_byte_index = self.0.iter().position(|&byte| byte == 0);
-
-        match null_byte_index {
-            Some(index) => {
-                // If a null byte is found, convert the slice up to that point to a string.
-                std::str::from_utf8(&self.0[..index]).unwrap_or_default()
-            }
-            None => {
-                // If no null byte is found, convert the entire array to a string.
-                std::str::from_utf8(&self.0).unwrap_or_default()
-            }
+    pub fn from_str(_env: &Env, s: &str) -> String {
+        String::from(s)
+    }
+
+    pub fn from_bytes(_env: &Env, b: &[u8]) -> String {
+        String {
+            data: Vec::new_from_slice(b),
+        }
+    }
+
+    pub fn env(&self) -> Env {
+        Env::default()
+    }
+
+    pub fn len(&self) -> u32 {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Copies the bytes of the string into `slice`.
+    ///
+    /// ### Panics
+    ///
+    /// If `slice` is not the same length as the string.
+    #[track_caller]
+    pub fn copy_into_slice(&self, slice: &mut [u8]) {
+        if slice.len() != self.data.len() as usize {
+            panic!("slice length does not match string length");
         }
+        slice.copy_from_slice(&self.data);
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        Bytes(self.data.clone())
+    }
+
+    /// Returns the string as `&str`, or `""` if it isn't valid UTF-8.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.data).unwrap_or_default()
     }
 
     pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+        &self.data
     }
 
-    pub fn to_le_bytes(&self) -> [u8; core::mem::size_of::<Self>()] {
-        self.0
+    /// Encodes the string as its length, as little-endian `u32`, followed by
+    /// its bytes, for use in `#[contracttype]` structs.
+    #[doc(hidden)]
+    pub fn to_le_bytes(&self) -> std::vec::Vec<u8> {
+        let mut bytes = self.len().to_le_bytes().to_vec();
+        bytes.extend_from_slice(&self.data);
+        bytes
     }
 
-    pub fn from_le_bytes(bytes: [u8; std::mem::size_of::<Self>()]) -> Self {
-        String(bytes)
+    /// Decodes a string encoded by [`String::to_le_bytes`] from the start of
+    /// `bytes`.
+    #[doc(hidden)]
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        let (len, data) = bytes.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        String {
+            data: Vec::new_from_slice(&data[..len]),
+        }
     }
 }
 
 impl From<&str> for String {
     fn from(s: &str) -> Self {
-        let mut bytes = [0; 10];
-
-        for (i, byte) in s.bytes().enumerate() {
-            if i >= 10 {
-                // Panic if the string is longer than the array size
-                // panic!("String is too long");
-                break;
-            }
-            bytes[i] = byte;
+        String {
+            data: Vec::new_from_slice(s.as_bytes()),
         }
+    }
+}
 
-        String(bytes)
+impl From<String> for Bytes {
+    fn from(s: String) -> Self {
+        Bytes(s.data)
     }
 }
 
 impl ToValEnum for crate::String {
     fn to_val(&self) -> Val {
-        Val::String(*self)
+        Val::String(self.clone())
     }
 }
 
@@ -79,13 +122,19 @@ impl FromValEnum for crate::String {
 #[cfg(any(kani, feature = "kani"))]
 impl kani::Arbitrary for crate::String {
     fn any() -> Self {
-        crate::String(kani::any::<[u8; 10]>())
+        let len: usize = kani::any();
+        kani::assume(len <= crate::vec::capacity());
+        let mut data = Vec::default();
+        for _ in 0..len {
+            data.push(kani::any());
+        }
+        crate::String { data }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{FromValEnum, ToValEnum};
+    use crate::{Env, FromValEnum, ToValEnum};
 
     #[test]
     fn test_string() {
@@ -97,4 +146,41 @@ mod test {
         let as_str = s2.as_str();
         assert_eq!(as_str, "hello");
     }
+
+    #[test]
+    fn test_string_not_truncated() {
+        let env = Env::default();
+        let s = crate::String::from_str(&env, "Stellar Lumens");
+        assert_eq!(s.len(), 14);
+        assert_eq!(s.to_string(), "Stellar Lumens");
+        let mut buf = [0u8; 14];
+        s.copy_into_slice(&mut buf);
+        assert_eq!(&buf, b"Stellar Lumens");
+        assert_eq!(s.to_bytes().as_slice(), b"Stellar Lumens");
+    }
+
+    #[test]
+    fn test_string_ordering() {
+        let env = Env::default();
+        let ab = crate::String::from_str(&env, "ab");
+        assert!(crate::String::from_str(&env, "a") < ab);
+        assert!(ab < crate::String::from_str(&env, "b"));
+        assert_eq!(ab, crate::String::from_bytes(&env, b"ab"));
+    }
+
+    #[test]
+    fn test_string_le_bytes() {
+        let s = crate::String::from("hello");
+        let mut bytes = s.to_le_bytes();
+        assert_eq!(bytes.len(), 9);
+        bytes.push(7);
+        assert_eq!(crate::String::from_le_bytes(&bytes), s);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice length does not match string length")]
+    fn test_string_copy_into_slice_length() {
+        let s = crate::String::from("hello");
+        s.copy_into_slice(&mut [0u8; 4]);
+    }
 }
@@ -3,12 +3,13 @@ use std::fmt::Display;
 
 use crate::{Env, FromValEnum, ToValEnum, Val};
 
-const SCSYMBOL_LIMIT: usize = 10;
+const SCSYMBOL_LIMIT: usize = 32;
+const SYMBOL_SHORT_LIMIT: usize = 9;
 
 #[derive(Debug)]
 pub enum SymbolError {
     /// Returned when attempting to form a [Symbol] from a string with more
-    /// than 10 characters.
+    /// than 32 characters.
     TooLong(usize),
     /// Returned when attempting to form a [Symbol] from
     /// a string with characters outside the range `[a-zA-Z0-9_]`.
@@ -24,7 +25,7 @@ impl Display for SymbolError {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default, Hash)]
 pub struct Symbol(pub [u8; SCSYMBOL_LIMIT]);
 
 impl fmt::Display for Symbol {
@@ -36,24 +37,17 @@ impl fmt::Display for Symbol {
 
 impl Symbol {
     pub fn as_str(&self) -> &str {
-        // Convert the internal byte array to a string slice
-        // Find the first null byte (0x00) in the byte array, if any.
-        let null_byte_index = self.0.iter().position(|&byte| byte == 0);
-
-        match null_byte_index {
-            Some(index) => {
-                // If a null byte is found, convert the slice up to that point to a string.
-                std::str::from_utf8(&self.0[..index]).unwrap_or_default()
-            }
-            None => {
-                // If no null byte is found, convert the entire array to a string.
-                std::str::from_utf8(&self.0).unwrap_or_default()
-            }
-        }
+        std::str::from_utf8(self.as_bytes()).unwrap_or_default()
     }
 
     pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+        // Symbols are padded with null bytes, which aren't valid characters.
+        let len = self
+            .0
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(SCSYMBOL_LIMIT);
+        &self.0[..len]
     }
 
     pub fn to_le_bytes(&self) -> [u8; SCSYMBOL_LIMIT] {
@@ -72,53 +66,54 @@ impl Symbol {
         Self::new_from_str(s)
     }
 
+    /// Creates a symbol of at most 9 characters, as used by `symbol_short!`.
+    ///
+    /// ### Panics
+    ///
+    /// If the symbol is invalid or longer than 9 characters. In a const
+    /// context this is a compile-time error.
+    pub const fn short(s: &str) -> Self {
+        if s.len() > SYMBOL_SHORT_LIMIT {
+            panic!("short symbols are limited to 9 characters");
+        }
+        Self::new_from_str(s)
+    }
+
+    /// Creates a symbol.
+    ///
+    /// ### Panics
+    ///
+    /// If the symbol is longer than 32 characters or has characters outside
+    /// `[a-zA-Z0-9_]`.
     pub const fn new_from_str(symbol: &str) -> Self {
-        let mut n = 0;
-        let sym_bytes = symbol.as_bytes();
-        let mut bytes = [b'\x00'; SCSYMBOL_LIMIT];
-        // Limit the number of bytes copied to the length of the symbol or 10, whichever is less
-        let limit = if sym_bytes.len() > SCSYMBOL_LIMIT {
-            SCSYMBOL_LIMIT
-        } else {
-            sym_bytes.len()
-        };
-        // Copy from sym_bytes to bytes
-        while n < limit {
-            bytes[n] = sym_bytes[n];
-            n += 1;
+        match Self::try_from_bytes(symbol.as_bytes()) {
+            Ok(symbol) => symbol,
+            Err(SymbolError::TooLong(_)) => panic!("symbols are limited to 32 characters"),
+            Err(SymbolError::BadChar(_)) => panic!("symbols can only contain [a-zA-Z0-9_]"),
         }
-        Symbol(bytes)
     }
 
     pub const fn try_from_bytes(b: &[u8]) -> Result<Self, SymbolError> {
+        if b.len() > SCSYMBOL_LIMIT {
+            return Err(SymbolError::TooLong(b.len()));
+        }
         let mut n = 0;
-        let mut _accum: u64 = 0;
-        let mut bytes = [0; 10];
+        let mut bytes = [0; SCSYMBOL_LIMIT];
         while n < b.len() {
-            let ch = b[n] as char;
-            if n >= SCSYMBOL_LIMIT {
-                return Err(SymbolError::TooLong(b.len()));
+            if let Err(e) = Self::check_char(b[n] as char) {
+                return Err(e);
             }
-            let v = match Self::encode_char(ch) {
-                Ok(v) => v,
-                Err(e) => return Err(e),
-            };
-            _accum |= v;
-            bytes[n] = v as u8;
+            bytes[n] = b[n];
             n += 1;
         }
         Ok(Symbol(bytes))
     }
 
-    const fn encode_char(ch: char) -> Result<u64, SymbolError> {
-        let v = match ch {
-            '_' => 1,
-            '0'..='9' => 2 + ((ch as u64) - ('0' as u64)),
-            'A'..='Z' => 12 + ((ch as u64) - ('A' as u64)),
-            'a'..='z' => 38 + ((ch as u64) - ('a' as u64)),
-            _ => return Err(SymbolError::BadChar(ch)),
-        };
-        Ok(v)
+    const fn check_char(ch: char) -> Result<(), SymbolError> {
+        match ch {
+            '_' | '0'..='9' | 'A'..='Z' | 'a'..='z' => Ok(()),
+            _ => Err(SymbolError::BadChar(ch)),
+        }
     }
 }
 
@@ -131,7 +126,10 @@ impl ToValEnum for Symbol {
 #[cfg(any(kani, feature = "kani"))]
 impl kani::Arbitrary for Symbol {
     fn any() -> Self {
-        let maybe_symbol = Symbol::try_from_bytes(&kani::any::<[u8; 10]>());
+        let len: usize = kani::any();
+        kani::assume(len <= SCSYMBOL_LIMIT);
+        let bytes = kani::any::<[u8; SCSYMBOL_LIMIT]>();
+        let maybe_symbol = Symbol::try_from_bytes(&bytes[..len]);
         kani::assume(maybe_symbol.is_ok());
         maybe_symbol.unwrap()
     }
@@ -172,4 +170,31 @@ mod test {
         let as_str = sym2.as_str();
         assert_eq!(as_str, s);
     }
+
+    #[test]
+    fn test_symbol_limits() {
+        let long = "abcdefghijklmnopqrstuvwxyz_01234";
+        assert_eq!(crate::Symbol::new_from_str(long).as_str(), long);
+        assert_eq!(crate::Symbol::short("increment").as_str(), "increment");
+        assert!(crate::Symbol::try_from_bytes(b"abcdefghijklmnopqrstuvwxyz_012345").is_err());
+        assert!(crate::Symbol::try_from_bytes(b"a-b").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "symbols are limited to 32 characters")]
+    fn test_symbol_too_long() {
+        crate::Symbol::new_from_str("abcdefghijklmnopqrstuvwxyz_012345");
+    }
+
+    #[test]
+    #[should_panic(expected = "symbols can only contain [a-zA-Z0-9_]")]
+    fn test_symbol_bad_char() {
+        crate::Symbol::new_from_str("a b");
+    }
+
+    #[test]
+    #[should_panic(expected = "short symbols are limited to 9 characters")]
+    fn test_symbol_short_too_long() {
+        crate::Symbol::short("increments");
+    }
 }
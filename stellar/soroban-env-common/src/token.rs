@@ -1,4 +1,9 @@
-use crate::{address::Address, env::Env, string::String};
+use core::marker::PhantomData;
+
+use crate::{
+    address::Address, dispatch::Invocation, env::Env, string::String, symbol::Symbol, FromValEnum,
+    ToValEnum, Val,
+};
 
 #[doc(hidden)]
 #[deprecated(note = "use TokenInterface")]
@@ -8,6 +13,16 @@ pub use TokenInterface as Interface;
 #[deprecated(note = "use TokenClient")]
 pub use TokenClient as Client;
 
+#[doc(hidden)]
+pub use StellarAssetClient as AdminClient;
+
+/// An allowance together with the ledger it expires after.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct MockToken {
     pub address: Address,
@@ -15,11 +30,35 @@ pub struct MockToken {
     pub symbol: String,
     pub decimals: u8,
     pub total_supply: i128,
-    pub balances: Vec<i128>,        // balances[owner_index]
-    pub allowances: Vec<Vec<i128>>, // allowances[owner_index][spender_index]
+    pub balances: Vec<i128>,                  // balances[owner_index]
+    pub allowances: Vec<Vec<AllowanceValue>>, // allowances[owner_index][spender_index]
+    pub authorized: Vec<bool>,                // authorized[holder_index]
     pub admin: Address,
 }
 
+fn check_nonnegative_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed: {}", amount)
+    }
+}
+
+/// Runs `f` as a call of `func` on the mock token at `address`, so the
+/// authorizations it requires are checked as they are for the SAC.
+fn mock_call<R>(
+    env: &Env,
+    address: &Address,
+    func: &str,
+    args: &[Val],
+    f: impl FnOnce() -> R,
+) -> R {
+    let invocation = Invocation {
+        contract: *address,
+        fn_name: Symbol::from(func),
+        args: crate::Vec::new_from_slice(args),
+    };
+    env.with_invocation(invocation, f)
+}
+
 impl MockToken {
     pub fn new(
         address: Address,
@@ -36,41 +75,137 @@ impl MockToken {
             decimals,
             total_supply,
             balances: vec![0; 100],
-            allowances: vec![vec![0; 100]; 100],
+            allowances: vec![vec![AllowanceValue::default(); 100]; 100],
+            authorized: vec![true; 100],
             admin,
         }
     }
+
+    pub fn balance(&self, id: &Address) -> i128 {
+        self.balances[id.val as usize]
+    }
+
+    /// Credits `amount` to `to`, panicking on overflow like the host does.
+    pub fn receive_balance(&mut self, to: &Address, amount: i128) {
+        let balance = &mut self.balances[to.val as usize];
+        *balance = balance.checked_add(amount).expect("balance overflow");
+    }
+
+    pub fn spend_balance(&mut self, from: &Address, amount: i128) {
+        let balance = &mut self.balances[from.val as usize];
+        assert!(*balance >= amount, "Insufficient balance");
+        *balance = balance.checked_sub(amount).expect("balance overflow");
+    }
+
+    /// Returns the allowance of `spender` over `from`'s balance, treating it
+    /// as zero once `sequence` is past its expiration ledger.
+    pub fn allowance(&self, from: &Address, spender: &Address, sequence: u32) -> i128 {
+        let allowance = self.allowances[from.val as usize][spender.val as usize];
+        if allowance.expiration_ledger < sequence {
+            0
+        } else {
+            allowance.amount
+        }
+    }
+
+    pub fn spend_allowance(
+        &mut self,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        sequence: u32,
+    ) {
+        let prev_allowance = self.allowance(from, spender, sequence);
+        assert!(prev_allowance >= amount, "Insufficient allowance");
+        self.allowances[from.val as usize][spender.val as usize].amount = prev_allowance - amount;
+    }
+
+    pub fn mint(&mut self, to: &Address, amount: i128) {
+        self.total_supply = self
+            .total_supply
+            .checked_add(amount)
+            .expect("total supply overflow");
+        self.receive_balance(to, amount);
+    }
+
+    pub fn burn(&mut self, from: &Address, amount: i128) {
+        self.spend_balance(from, amount);
+        self.total_supply = self
+            .total_supply
+            .checked_sub(amount)
+            .expect("total supply overflow");
+    }
+
+    pub fn is_authorized(&self, id: &Address) -> bool {
+        self.authorized[id.val as usize]
+    }
+
+    /// Panics if `id` has been deauthorized by the admin, like the SAC does
+    /// for any balance change other than a clawback.
+    pub fn require_authorized(&self, id: &Address) {
+        assert!(self.is_authorized(id), "balance is deauthorized");
+    }
 }
 
 #[derive(Clone, Debug)]
-pub struct TokenClient {
+pub struct TokenClient<'a> {
     pub env: Env,
     pub address: Address,
+    _phantom: PhantomData<&'a ()>,
 }
 
-impl TokenClient {
+impl<'a> TokenClient<'a> {
     pub fn new(env: &Env, address: &Address) -> Self {
         Self {
             env: env.clone(),
             address: *address,
+            _phantom: PhantomData,
         }
     }
 
-    pub fn decimals(&self, env: Env) -> u32 {
+    pub fn decimals(&self) -> u32 {
+        if self.is_contract() {
+            return self.invoke("decimals", &[]);
+        }
+
         let token = self.get_self_token();
         token.decimals as u32
     }
 
-    pub fn name(&self, env: Env) -> String {
+    pub fn name(&self) -> String {
+        if self.is_contract() {
+            return self.invoke("name", &[]);
+        }
+
         let token = self.get_self_token();
         token.name
     }
 
-    pub fn symbol(&self, env: Env) -> String {
+    pub fn symbol(&self) -> String {
+        if self.is_contract() {
+            return self.invoke("symbol", &[]);
+        }
+
         let token = self.get_self_token();
         token.symbol
     }
 
+    /// Returns whether the token is a contract registered in the `Env`,
+    /// rather than a `MockToken`.
+    fn is_contract(&self) -> bool {
+        self.env.has_contract(&self.address)
+    }
+
+    /// Calls `func` on the registered token contract.
+    fn invoke<T: FromValEnum>(&self, func: &str, args: &[Val]) -> T {
+        self.env
+            .invoke_registered(&self.address, &Symbol::from(func), args)
+    }
+
+    fn mock_call<R>(&self, func: &str, args: &[Val], f: impl FnOnce() -> R) -> R {
+        mock_call(&self.env, &self.address, func, args, f)
+    }
+
     pub fn get_self_token(&self) -> MockToken {
         let token = self.env.storage.borrow().get_token(&self.address);
         if let Some(token) = token {
@@ -81,135 +216,228 @@ impl TokenClient {
     }
 
     pub fn balance(&self, address: &Address) -> i128 {
+        if self.is_contract() {
+            return self.invoke("balance", &[address.to_val()]);
+        }
+
         let token = self.get_self_token();
-        token.balances[address.val as usize]
+        token.balance(address)
     }
 
     pub fn transfer(&self, from: &Address, to: &Address, amount: &i128) {
-        let mut token = self.get_self_token();
-        let prev_bal_from = self.balance(from);
-
-        assert!(prev_bal_from >= *amount);
-
-        let prev_bal_to = self.balance(to);
-
-        let new_bal_from = prev_bal_from.saturating_sub(*amount);
-        let new_bal_to = prev_bal_to.saturating_add(*amount);
-
-        token.balances[from.val as usize] = new_bal_from;
-        token.balances[to.val as usize] = new_bal_to;
+        if self.is_contract() {
+            self.invoke::<Val>("transfer", &[from.to_val(), to.to_val(), amount.to_val()]);
+            return;
+        }
 
-        self.env.storage.borrow_mut().update_token(token.clone());
+        self.mock_call(
+            "transfer",
+            &[from.to_val(), to.to_val(), amount.to_val()],
+            || {
+                check_nonnegative_amount(*amount);
+                from.require_auth();
+
+                let mut token = self.get_self_token();
+                token.require_authorized(from);
+                token.require_authorized(to);
+
+                token.spend_balance(from, *amount);
+                token.receive_balance(to, *amount);
+
+                self.env.storage.borrow_mut().update_token(token.clone());
+                self.env.publish_event(
+                    self.address,
+                    (Symbol::from("transfer"), *from, *to),
+                    *amount,
+                );
+            },
+        )
     }
 
+    /// Mints through the SAC admin interface; use
+    /// [`StellarAssetClient::mint`], as SEP-41 tokens have no `mint`.
+    #[doc(hidden)]
     pub fn mint(&self, to: &Address, amount: &i128) {
-        assert!(*amount > 0, "Mint amount must be positive");
-
-        let mut token = self.get_self_token();
-        let prev_bal = self.balance(to);
-
-        let new_bal = prev_bal.saturating_add(*amount);
-
-        token.balances[to.val as usize] = new_bal;
-        self.env.storage.borrow_mut().update_token(token.clone());
+        StellarAssetClient::new(&self.env, &self.address).mint(to, amount);
     }
 
     pub fn burn(&self, from: &Address, amount: &i128) {
-        let mut token = self.get_self_token();
-        let prev_bal = self.balance(from);
+        if self.is_contract() {
+            self.invoke::<Val>("burn", &[from.to_val(), amount.to_val()]);
+            return;
+        }
 
-        assert!(prev_bal >= *amount, "Insufficient balance");
+        self.mock_call("burn", &[from.to_val(), amount.to_val()], || {
+            check_nonnegative_amount(*amount);
+            from.require_auth();
 
-        let new_bal = prev_bal.saturating_sub(*amount);
+            let mut token = self.get_self_token();
+            token.require_authorized(from);
 
-        token.balances[from.val as usize] = new_bal;
-        self.env.storage.borrow_mut().update_token(token.clone());
+            token.burn(from, *amount);
+
+            self.env.storage.borrow_mut().update_token(token.clone());
+            self.env
+                .publish_event(self.address, (Symbol::from("burn"), *from), *amount);
+        })
     }
 
     /// Set the allowance by `amount` for `spender` to transfer/burn from
     /// `from`.
     pub fn approve(
         &self,
-        env: Env,
-        from: Address,
-        spender: Address,
-        amount: i128,
-        _expiration_ledger: u32,
+        from: &Address,
+        spender: &Address,
+        amount: &i128,
+        expiration_ledger: &u32,
     ) {
-        let mut token = self.get_self_token();
-        token.allowances[from.val as usize][spender.val as usize] = amount;
-        self.env.storage.borrow_mut().update_token(token.clone());
+        if self.is_contract() {
+            self.invoke::<Val>(
+                "approve",
+                &[
+                    from.to_val(),
+                    spender.to_val(),
+                    amount.to_val(),
+                    expiration_ledger.to_val(),
+                ],
+            );
+            return;
+        }
+
+        self.mock_call(
+            "approve",
+            &[
+                from.to_val(),
+                spender.to_val(),
+                amount.to_val(),
+                expiration_ledger.to_val(),
+            ],
+            || {
+                check_nonnegative_amount(*amount);
+                from.require_auth();
+
+                let mut token = self.get_self_token();
+                if *amount > 0 && *expiration_ledger < self.env.ledger_info().sequence_number {
+                    panic!("expiration_ledger is less than ledger seq when amount > 0")
+                }
+                token.allowances[from.val as usize][spender.val as usize] = AllowanceValue {
+                    amount: *amount,
+                    expiration_ledger: *expiration_ledger,
+                };
+                self.env.storage.borrow_mut().update_token(token.clone());
+                self.env.publish_event(
+                    self.address,
+                    (Symbol::from("approve"), *from, *spender),
+                    (*amount, *expiration_ledger),
+                );
+            },
+        )
     }
 
     /// Returns the allowance for `spender` to transfer from `from`.
-    pub fn allowance(&self, env: Env, from: Address, spender: Address) -> i128 {
+    pub fn allowance(&self, from: &Address, spender: &Address) -> i128 {
+        if self.is_contract() {
+            return self.invoke("allowance", &[from.to_val(), spender.to_val()]);
+        }
+
         let token = self.get_self_token();
-        token.allowances[from.val as usize][spender.val as usize]
+        token.allowance(from, spender, self.env.ledger_info().sequence_number)
     }
 
     /// Transfer `amount` from `from` to `to`, consuming the allowance of
     /// `spender`. Authorized by spender (`spender.require_auth()`).
-    pub fn transfer_from(
-        &self,
-        env: Env,
-        spender: Address,
-        from: Address,
-        to: Address,
-        amount: i128,
-    ) {
-        let mut token = self.get_self_token();
-        let prev_bal_from = self.balance(&from);
-
-        assert!(prev_bal_from >= amount, "Insufficient balance");
-
-        let prev_allowance = self.allowance(env, from, spender);
-        assert!(prev_allowance >= amount, "Insufficient allowance");
-
-        let prev_bal_to = self.balance(&to);
-
-        let new_bal_from = prev_bal_from.saturating_sub(amount);
-        let new_bal_to = prev_bal_to.saturating_add(amount);
-
-        let new_allowance = prev_allowance.saturating_sub(amount);
-
-        token.balances[from.val as usize] = new_bal_from;
-        token.balances[to.val as usize] = new_bal_to;
-        token.allowances[from.val as usize][spender.val as usize] = new_allowance;
+    pub fn transfer_from(&self, spender: &Address, from: &Address, to: &Address, amount: &i128) {
+        if self.is_contract() {
+            self.invoke::<Val>(
+                "transfer_from",
+                &[
+                    spender.to_val(),
+                    from.to_val(),
+                    to.to_val(),
+                    amount.to_val(),
+                ],
+            );
+            return;
+        }
 
-        self.env.storage.borrow_mut().update_token(token.clone());
+        self.mock_call(
+            "transfer_from",
+            &[
+                spender.to_val(),
+                from.to_val(),
+                to.to_val(),
+                amount.to_val(),
+            ],
+            || {
+                check_nonnegative_amount(*amount);
+                spender.require_auth();
+
+                let mut token = self.get_self_token();
+                token.require_authorized(from);
+                token.require_authorized(to);
+
+                let sequence = self.env.ledger_info().sequence_number;
+                token.spend_allowance(from, spender, *amount, sequence);
+                token.spend_balance(from, *amount);
+                token.receive_balance(to, *amount);
+
+                self.env.storage.borrow_mut().update_token(token.clone());
+                self.env.publish_event(
+                    self.address,
+                    (Symbol::from("transfer"), *from, *to),
+                    *amount,
+                );
+            },
+        )
     }
 
     /// Burn `amount` from `from`, consuming the allowance of `spender`.
-    pub fn burn_from(&self, env: Env, spender: Address, from: Address, amount: i128) {
-        let mut token = self.get_self_token();
-        let prev_bal_from = self.balance(&from);
-
-        assert!(prev_bal_from >= amount, "Insufficient balance");
-
-        let prev_allowance = self.allowance(env, from, spender);
-        assert!(prev_allowance >= amount, "Insufficient allowance");
-
-        let new_bal_from = prev_bal_from.saturating_sub(amount);
-        let new_allowance = prev_allowance.saturating_sub(amount);
-
-        token.balances[from.val as usize] = new_bal_from;
-        token.allowances[from.val as usize][spender.val as usize] = new_allowance;
+    pub fn burn_from(&self, spender: &Address, from: &Address, amount: &i128) {
+        if self.is_contract() {
+            self.invoke::<Val>(
+                "burn_from",
+                &[spender.to_val(), from.to_val(), amount.to_val()],
+            );
+            return;
+        }
 
-        self.env.storage.borrow_mut().update_token(token.clone());
+        self.mock_call(
+            "burn_from",
+            &[spender.to_val(), from.to_val(), amount.to_val()],
+            || {
+                check_nonnegative_amount(*amount);
+                spender.require_auth();
+
+                let mut token = self.get_self_token();
+                token.require_authorized(from);
+
+                let sequence = self.env.ledger_info().sequence_number;
+                token.spend_allowance(from, spender, *amount, sequence);
+                token.burn(from, *amount);
+
+                self.env.storage.borrow_mut().update_token(token.clone());
+                self.env
+                    .publish_event(self.address, (Symbol::from("burn"), *from), *amount);
+            },
+        )
     }
 }
 
+/// Client for a Stellar Asset Contract: the SEP-41 functions of
+/// [`TokenInterface`] and the admin functions of [`StellarAssetInterface`].
 #[derive(Clone, Debug)]
-pub struct AdminClient {
+pub struct StellarAssetClient<'a> {
     pub env: Env,
     pub address: Address,
+    _phantom: PhantomData<&'a ()>,
 }
 
-impl AdminClient {
+impl<'a> StellarAssetClient<'a> {
     pub fn new(env: &Env, address: &Address) -> Self {
         Self {
             env: env.clone(),
             address: *address,
+            _phantom: PhantomData,
         }
     }
 
@@ -226,36 +454,193 @@ impl AdminClient {
         self.env.storage.borrow_mut().update_token(token.clone());
     }
 
+    /// Returns whether the token is a contract registered in the `Env`,
+    /// rather than a `MockToken`.
+    fn is_contract(&self) -> bool {
+        self.env.has_contract(&self.address)
+    }
+
+    /// Calls `func` on the registered token contract.
+    fn invoke<T: FromValEnum>(&self, func: &str, args: &[Val]) -> T {
+        self.env
+            .invoke_registered(&self.address, &Symbol::from(func), args)
+    }
+
+    fn mock_call<R>(&self, func: &str, args: &[Val], f: impl FnOnce() -> R) -> R {
+        mock_call(&self.env, &self.address, func, args, f)
+    }
+
+    /// Returns a client for the SEP-41 functions of the same token.
+    fn token(&self) -> TokenClient<'a> {
+        TokenClient::new(&self.env, &self.address)
+    }
+
+    pub fn allowance(&self, from: &Address, spender: &Address) -> i128 {
+        self.token().allowance(from, spender)
+    }
+
+    pub fn approve(
+        &self,
+        from: &Address,
+        spender: &Address,
+        amount: &i128,
+        expiration_ledger: &u32,
+    ) {
+        self.token()
+            .approve(from, spender, amount, expiration_ledger)
+    }
+
     pub fn balance(&self, address: &Address) -> i128 {
-        let token = self.get_self_token();
-        token.balances[address.val as usize]
+        self.token().balance(address)
+    }
+
+    pub fn transfer(&self, from: &Address, to: &Address, amount: &i128) {
+        self.token().transfer(from, to, amount)
+    }
+
+    pub fn transfer_from(&self, spender: &Address, from: &Address, to: &Address, amount: &i128) {
+        self.token().transfer_from(spender, from, to, amount)
+    }
+
+    pub fn burn(&self, from: &Address, amount: &i128) {
+        self.token().burn(from, amount)
+    }
+
+    pub fn burn_from(&self, spender: &Address, from: &Address, amount: &i128) {
+        self.token().burn_from(spender, from, amount)
+    }
+
+    pub fn decimals(&self) -> u32 {
+        self.token().decimals()
+    }
+
+    pub fn name(&self) -> String {
+        self.token().name()
+    }
+
+    pub fn symbol(&self) -> String {
+        self.token().symbol()
     }
 
     pub fn mint(&self, to: &Address, amount: &i128) {
-        assert!(*amount > 0);
+        if self.is_contract() {
+            self.invoke::<Val>("mint", &[to.to_val(), amount.to_val()]);
+            return;
+        }
+
+        self.mock_call("mint", &[to.to_val(), amount.to_val()], || {
+            check_nonnegative_amount(*amount);
 
-        let mut token = self.get_self_token();
-        let prev_bal = self.balance(to);
+            let mut token = self.get_self_token();
+            token.admin.require_auth();
+            token.require_authorized(to);
 
-        let new_bal = prev_bal.saturating_add(*amount);
+            token.mint(to, *amount);
 
-        token.balances[to.val as usize] = new_bal;
-        self.update_self_token(&token);
+            self.update_self_token(&token);
+            self.env.publish_event(
+                self.address,
+                (Symbol::from("mint"), token.admin, *to),
+                *amount,
+            );
+        })
     }
 
     pub fn admin(&self) -> Address {
+        if self.is_contract() {
+            return self.invoke("admin", &[]);
+        }
+
         let token = self.get_self_token();
         token.admin
     }
 
     pub fn set_admin(&self, new_admin: &Address) {
-        let mut token = self.get_self_token();
-        token.admin = *new_admin;
-        self.env.storage.borrow_mut().update_token(token.clone());
+        if self.is_contract() {
+            self.invoke::<Val>("set_admin", &[new_admin.to_val()]);
+            return;
+        }
+
+        self.mock_call("set_admin", &[new_admin.to_val()], || {
+            let mut token = self.get_self_token();
+            let admin = token.admin;
+            admin.require_auth();
+            token.admin = *new_admin;
+            self.update_self_token(&token);
+            self.env
+                .publish_event(self.address, (Symbol::from("set_admin"), admin), *new_admin);
+        })
+    }
+
+    /// Sets whether `id` is allowed to use its balance. Deauthorized holders
+    /// can neither send nor receive tokens, but can still be clawed back.
+    pub fn set_authorized(&self, id: &Address, authorize: &bool) {
+        if self.is_contract() {
+            self.invoke::<Val>("set_authorized", &[id.to_val(), authorize.to_val()]);
+            return;
+        }
+
+        self.mock_call("set_authorized", &[id.to_val(), authorize.to_val()], || {
+            let mut token = self.get_self_token();
+            token.admin.require_auth();
+            token.authorized[id.val as usize] = *authorize;
+            self.update_self_token(&token);
+            self.env.publish_event(
+                self.address,
+                (Symbol::from("set_authorized"), token.admin, *id),
+                *authorize,
+            );
+        })
+    }
+
+    pub fn authorized(&self, id: &Address) -> bool {
+        if self.is_contract() {
+            return self.invoke("authorized", &[id.to_val()]);
+        }
+
+        let token = self.get_self_token();
+        token.is_authorized(id)
+    }
+
+    /// Burns `amount` from `from` regardless of its authorization state.
+    pub fn clawback(&self, from: &Address, amount: &i128) {
+        if self.is_contract() {
+            self.invoke::<Val>("clawback", &[from.to_val(), amount.to_val()]);
+            return;
+        }
+
+        self.mock_call("clawback", &[from.to_val(), amount.to_val()], || {
+            check_nonnegative_amount(*amount);
+
+            let mut token = self.get_self_token();
+            token.admin.require_auth();
+
+            token.burn(from, *amount);
+
+            self.update_self_token(&token);
+            self.env.publish_event(
+                self.address,
+                (Symbol::from("clawback"), token.admin, *from),
+                *amount,
+            );
+        })
     }
 }
 
+/// The SEP-41 token interface.
+///
+/// The `verify_*` hooks check each function against the reference
+/// properties in `token_spec`, calling the token registered at `token`;
+/// `#[contractimpl]` turns them into Kani harnesses for every contract
+/// implementing this trait.
 pub trait TokenInterface {
+    /// Puts a freshly registered token into a state where `id` holds
+    /// `amount` tokens, for use by the `verify_*` harnesses.
+    ///
+    /// Runs as the token contract, so it can call the token's own
+    /// functions, such as its initializer and `mint`.
+    fn verify_setup(env: &Env, id: &Address, amount: i128);
+
     /// Returns the allowance for `spender` to transfer from `from`.
     ///
     /// # Arguments
@@ -263,7 +648,10 @@ pub trait TokenInterface {
     /// * `from` - The address holding the balance of tokens to be drawn from.
     /// * `spender` - The address spending the tokens held by `from`.
     fn allowance(env: Env, from: Address, spender: Address) -> i128;
-    fn verify_allowance() {}
+    fn verify_allowance(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_allowance::<Self>(_env, _token);
+    }
 
     /// Set the allowance by `amount` for `spender` to transfer/burn from
     /// `from`.
@@ -275,16 +663,19 @@ pub trait TokenInterface {
     ///   `from`.
     /// * `amount` - The tokens to be made available to `spender`.
     /// * `expiration_ledger` - The ledger number where this allowance expires. Cannot
-    ///    be less than the current ledger number unless the amount is being set to 0.
-    ///    An expired entry (where expiration_ledger < the current ledger number)
-    ///    should be treated as a 0 amount allowance.
+    ///   be less than the current ledger number unless the amount is being set to 0.
+    ///   An expired entry (where expiration_ledger < the current ledger number)
+    ///   should be treated as a 0 amount allowance.
     ///
     /// # Events
     ///
     /// Emits an event with topics `["approve", from: Address,
     /// spender: Address], data = [amount: i128, expiration_ledger: u32]`
     fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32);
-    fn verify_approve() {}
+    fn verify_approve(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_approve::<Self>(_env, _token);
+    }
 
     /// Returns the balance of `id`.
     ///
@@ -293,7 +684,10 @@ pub trait TokenInterface {
     /// * `id` - The address for which a balance is being queried. If the
     ///   address has no existing balance, returns 0.
     fn balance(env: Env, id: Address) -> i128;
-    fn verify_balance() {}
+    fn verify_balance(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_balance::<Self>(_env, _token);
+    }
 
     /// Transfer `amount` from `from` to `to`.
     ///
@@ -309,7 +703,10 @@ pub trait TokenInterface {
     /// Emits an event with topics `["transfer", from: Address, to: Address],
     /// data = [amount: i128]`
     fn transfer(env: Env, from: Address, to: Address, amount: i128);
-    fn verify_transfer() {}
+    fn verify_transfer(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_transfer::<Self>(_env, _token);
+    }
 
     /// Transfer `amount` from `from` to `to`, consuming the allowance of
     /// `spender`. Authorized by spender (`spender.require_auth()`).
@@ -328,7 +725,10 @@ pub trait TokenInterface {
     /// Emits an event with topics `["transfer", from: Address, to: Address],
     /// data = [amount: i128]`
     fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128);
-    fn verify_transfer_from() {}
+    fn verify_transfer_from(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_transfer_from::<Self>(_env, _token);
+    }
 
     /// Burn `amount` from `from`.
     ///
@@ -343,7 +743,10 @@ pub trait TokenInterface {
     /// Emits an event with topics `["burn", from: Address], data = [amount:
     /// i128]`
     fn burn(env: Env, from: Address, amount: i128);
-    fn verify_burn() {}
+    fn verify_burn(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_burn::<Self>(_env, _token);
+    }
 
     /// Burn `amount` from `from`, consuming the allowance of `spender`.
     ///
@@ -360,7 +763,10 @@ pub trait TokenInterface {
     /// Emits an event with topics `["burn", from: Address], data = [amount:
     /// i128]`
     fn burn_from(env: Env, spender: Address, from: Address, amount: i128);
-    fn verify_burn_from() {}
+    fn verify_burn_from(_env: &Env, _token: &Address) {
+        #[cfg(any(kani, feature = "kani"))]
+        crate::token_spec::verify_burn_from::<Self>(_env, _token);
+    }
 
     /// Returns the number of decimals used to represent amounts of this token.
     ///
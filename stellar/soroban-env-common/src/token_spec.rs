@@ -0,0 +1,175 @@
+//! Reference SEP-41 properties checked against any [`TokenInterface`]
+//! implementation through its `verify_*` hooks.
+//!
+//! Each property runs against a token contract registered in the `Env`,
+//! and calls it through a [`TokenClient`] like any other contract would, so
+//! the calls go through dispatch and the authorization model.
+use crate::{
+    token::{TokenClient, TokenInterface},
+    Address, Env, LedgerInfo,
+};
+
+fn any_amount() -> i128 {
+    let amount = kani::any::<i128>();
+    kani::assume(amount >= 0);
+    amount
+}
+
+/// Funds a holder of `token` through [`TokenInterface::verify_setup`], run
+/// as the token contract, and returns the holder with its balance.
+fn funded_holder<T: TokenInterface + ?Sized>(env: &Env, token: &Address) -> (Address, i128) {
+    let holder = Address::new(env);
+    let amount = any_amount();
+    env.as_contract(token, || T::verify_setup(env, &holder, amount));
+    let balance = TokenClient::new(env, token).balance(&holder);
+    (holder, balance)
+}
+
+/// Asserts that the last call into `token` required the authorization of
+/// `address`.
+fn assert_required_auth(env: &Env, token: &Address, address: &Address) {
+    assert!(env
+        .auths()
+        .iter()
+        .any(|auth| auth.address == *address && auth.contract == *token));
+}
+
+/// A fresh allowance is zero, and an allowance reads as zero once the
+/// ledger moves past its expiration.
+pub fn verify_allowance<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, _) = funded_holder::<T>(env, token);
+    let spender = Address::new(env);
+    assert_eq!(client.allowance(&from, &spender), 0);
+
+    let sequence = env.ledger_info().sequence_number;
+    let expiration_ledger = kani::any::<u32>();
+    kani::assume(expiration_ledger >= sequence && expiration_ledger < u32::MAX);
+    client.approve(&from, &spender, &any_amount(), &expiration_ledger);
+
+    env.set_ledger_info(LedgerInfo {
+        sequence_number: expiration_ledger + 1,
+        ..env.ledger_info()
+    });
+    assert_eq!(client.allowance(&from, &spender), 0);
+}
+
+/// Approving requires the authorization of `from` and sets the allowance
+/// to exactly the approved amount.
+pub fn verify_approve<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, _) = funded_holder::<T>(env, token);
+    let spender = Address::new(env);
+    let amount = any_amount();
+    let expiration_ledger = kani::any::<u32>();
+    kani::assume(expiration_ledger >= env.ledger_info().sequence_number);
+
+    client.approve(&from, &spender, &amount, &expiration_ledger);
+    assert_required_auth(env, token, &from);
+
+    assert_eq!(client.allowance(&from, &spender), amount);
+}
+
+/// Balances are never negative and unknown holders have a zero balance.
+pub fn verify_balance<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (_, balance) = funded_holder::<T>(env, token);
+    let other = Address::new(env);
+
+    assert!(balance >= 0);
+    assert_eq!(client.balance(&other), 0);
+}
+
+/// A transfer requires the authorization of `from`, moves exactly `amount`
+/// between the two holders and conserves their combined balance.
+pub fn verify_transfer<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, from_balance) = funded_holder::<T>(env, token);
+    let to = Address::new(env);
+    let to_balance = client.balance(&to);
+    let amount = any_amount();
+    kani::assume(amount <= from_balance);
+
+    client.transfer(&from, &to, &amount);
+    assert_required_auth(env, token, &from);
+
+    let new_from_balance = client.balance(&from);
+    let new_to_balance = client.balance(&to);
+    assert_eq!(new_from_balance, from_balance - amount);
+    assert_eq!(new_to_balance, to_balance + amount);
+    assert!(new_from_balance >= 0);
+}
+
+/// `transfer_from` requires the authorization of the spender, decrements
+/// its allowance by `amount` and moves exactly `amount` from `from` to `to`.
+pub fn verify_transfer_from<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, from_balance) = funded_holder::<T>(env, token);
+    let spender = Address::new(env);
+    let to = Address::new(env);
+    let allowance = any_amount();
+    client.approve(
+        &from,
+        &spender,
+        &allowance,
+        &env.ledger_info().sequence_number,
+    );
+    let to_balance = client.balance(&to);
+    let amount = any_amount();
+    kani::assume(amount <= allowance && amount <= from_balance);
+
+    client.transfer_from(&spender, &from, &to, &amount);
+    assert_required_auth(env, token, &spender);
+
+    assert_eq!(client.allowance(&from, &spender), allowance - amount);
+    assert_eq!(client.balance(&from), from_balance - amount);
+    assert_eq!(client.balance(&to), to_balance + amount);
+}
+
+/// Burning requires the authorization of `from` and reduces the total
+/// supply by exactly `amount`.
+///
+/// Only the funded holder has a balance, so the supply is the sum of the
+/// balances of the holders involved.
+pub fn verify_burn<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, from_balance) = funded_holder::<T>(env, token);
+    let other = Address::new(env);
+    let supply = from_balance + client.balance(&other);
+    let amount = any_amount();
+    kani::assume(amount <= from_balance);
+
+    client.burn(&from, &amount);
+    assert_required_auth(env, token, &from);
+
+    let new_from_balance = client.balance(&from);
+    assert_eq!(new_from_balance, from_balance - amount);
+    assert_eq!(new_from_balance + client.balance(&other), supply - amount);
+}
+
+/// `burn_from` requires the authorization of the spender, decrements its
+/// allowance and reduces the total supply by exactly `amount`, all of it
+/// taken from `from`.
+pub fn verify_burn_from<T: TokenInterface + ?Sized>(env: &Env, token: &Address) {
+    let client = TokenClient::new(env, token);
+    let (from, from_balance) = funded_holder::<T>(env, token);
+    let spender = Address::new(env);
+    let allowance = any_amount();
+    client.approve(
+        &from,
+        &spender,
+        &allowance,
+        &env.ledger_info().sequence_number,
+    );
+    let supply = from_balance + client.balance(&spender);
+    let amount = any_amount();
+    kani::assume(amount <= allowance && amount <= from_balance);
+
+    client.burn_from(&spender, &from, &amount);
+    assert_required_auth(env, token, &spender);
+
+    let new_from_balance = client.balance(&from);
+    assert_eq!(client.allowance(&from, &spender), allowance - amount);
+    assert_eq!(new_from_balance, from_balance - amount);
+    assert_eq!(new_from_balance + client.balance(&spender), supply - amount);
+}
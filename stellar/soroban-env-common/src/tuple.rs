@@ -1,4 +1,6 @@
-use crate::{FromValEnum, ToValEnum};
+use std::rc::Rc;
+
+use crate::{Env, FromValEnum, IntoVal, ToValEnum, Val, Vec};
 
 macro_rules! find_len_idx {
     () => { 0 };
@@ -11,20 +13,28 @@ macro_rules! derive_to_val_for_tuples {
     ( $($typ:ident $idx:tt)* ) => (
         impl<$($typ:ToValEnum),*> ToValEnum for ($($typ,)*) {
             fn to_val(&self) -> crate::Val {
-                let tuple = vec![$(self.$idx.to_val()),*];
-                crate::Val::TupleVal(tuple)
+                let tuple = [$(Rc::new(self.$idx.to_val())),*];
+                crate::Val::VecVal(Vec::from(tuple))
+            }
+        }
+
+        impl<$($typ:ToValEnum),*> IntoVal<Env, Vec<Val>> for ($($typ,)*) {
+            fn into_val(self, env: &Env) -> Vec<Val> {
+                let mut args = Vec::new(env);
+                $(args.push(self.$idx.to_val());)*
+                args
             }
         }
 
         impl<$($typ:FromValEnum),*> FromValEnum for ($($typ,)*) {
             fn from_val(val: crate::Val) -> Option<Self> {
                 match val {
-                    crate::Val::TupleVal(tuple) => {
-                        if tuple.len() != find_len_idx!($($idx)*) + 1 {
+                    crate::Val::VecVal(tuple) => {
+                        if tuple.len() as usize != find_len_idx!($($idx)*) + 1 {
                             return None;
                         }
                         Some(($(
-                            $typ::from_val(tuple[$idx].clone())?,
+                            $typ::from_val((*tuple[$idx]).clone())?,
                         )*))
                     }
                     _ => None,
@@ -34,7 +44,25 @@ macro_rules! derive_to_val_for_tuples {
     );
 }
 
-derive_to_val_for_tuples! {}
+// The unit tuple is `Void`, as on the host.
+impl ToValEnum for () {
+    fn to_val(&self) -> Val {
+        Val::Void
+    }
+}
+
+impl IntoVal<Env, Vec<Val>> for () {
+    fn into_val(self, env: &Env) -> Vec<Val> {
+        Vec::new(env)
+    }
+}
+
+impl FromValEnum for () {
+    fn from_val(val: Val) -> Option<Self> {
+        matches!(val, Val::Void).then_some(())
+    }
+}
+
 derive_to_val_for_tuples! { T0 0}
 derive_to_val_for_tuples! { T0 0 T1 1}
 derive_to_val_for_tuples! { T0 0 T1 1 T2 2}
@@ -1,12 +1,11 @@
 use std::rc::Rc;
 
 use crate::{
-    enums::EnumType,
-    num::{Duration, Timepoint},
+    num::{Duration, Timepoint, I256, U256},
     symbol::Symbol,
 };
 
-#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Val {
     SymbolVal(Symbol),
     I32Val(i32),
@@ -21,14 +20,13 @@ pub enum Val {
     AddressObj(u32),
     I128(i128),
     U128(u128),
+    U256Val(U256),
+    I256Val(I256),
     #[default]
     Void,
-    Struct(Vec<u8>),
     VecVal(crate::Vec<Rc<Val>>),
-    EnumVal(EnumType),
     BytesNVal(crate::Vec<u8>),
     MapVal(Vec<(Val, Val)>),
-    TupleVal(Vec<Val>),
 }
 
 impl Val {
@@ -115,6 +113,28 @@ impl ToValEnum for bool {
     }
 }
 
+impl FromValEnum for bool {
+    fn from_val(val: Val) -> Option<Self> {
+        if let Val::BoolVal(b) = val {
+            Some(b)
+        } else {
+            None
+        }
+    }
+}
+
+impl ToValEnum for Val {
+    fn to_val(&self) -> Val {
+        self.clone()
+    }
+}
+
+impl FromValEnum for Val {
+    fn from_val(val: Val) -> Option<Self> {
+        Some(val)
+    }
+}
+
 impl<T> FromValEnum for Option<T>
 where
     T: FromValEnum,
@@ -1,218 +1,464 @@
-use core::slice;
-use std::{borrow::Borrow, ops, rc::Rc};
+use std::{
+    borrow::Borrow,
+    cell::Cell,
+    iter::Rev,
+    ops::{self, Bound, RangeBounds},
+    rc::Rc,
+};
 
 use crate::{Env, FromValEnum, ToValEnum, Val};
 
-const VEC_SIZE: usize = 10;
+/// Default maximum number of elements in a `Vec`, enough for a 32-byte hash
+/// in `Bytes`.
+pub const DEFAULT_CAPACITY: usize = 32;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+thread_local! {
+    static CAPACITY: Cell<usize> = const { Cell::new(DEFAULT_CAPACITY) };
+}
+
+/// Returns the maximum number of elements a `Vec` can hold.
+pub fn capacity() -> usize {
+    CAPACITY.with(|capacity| capacity.get())
+}
+
+/// Sets the maximum number of elements a `Vec` can hold. Smaller capacities
+/// keep Kani harnesses tractable, larger ones allow realistic data sizes.
+///
+/// `Vec`s don't belong to an `Env`, so the capacity applies to every `Vec`
+/// created on the current thread, whatever its `Env`, until it is set
+/// again.
+pub fn set_capacity(capacity: usize) {
+    CAPACITY.with(|cell| cell.set(capacity));
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct Vec<T> {
-    pub data: [T; VEC_SIZE],
-    pub size: usize,
+    data: std::vec::Vec<T>,
 }
 
 #[derive(Debug)]
 pub struct VecIterator<'a, T> {
     vec: &'a Vec<T>,
     idx: usize,
+    end: usize,
 }
 
 pub struct VecIntoIterator<T> {
-    vec: Vec<T>,
-    idx: usize,
+    iter: std::vec::IntoIter<T>,
 }
 
-impl<T: Default> Default for Vec<T> {
+impl<T> Default for Vec<T> {
     fn default() -> Self {
         Vec {
-            data: Default::default(),
-            size: 0,
+            data: std::vec::Vec::new(),
         }
     }
 }
 
+#[track_caller]
+fn index_out_of_bounds() -> ! {
+    panic!("index out of bounds")
+}
+
 impl<T> Vec<T> {
-    pub fn new(_env: &Env) -> Vec<T>
-    where
-        T: Default,
-    {
+    pub fn new(_env: &Env) -> Vec<T> {
+        Vec::default()
+    }
+
+    pub fn with_capacity(s: usize) -> Vec<T> {
         Vec {
-            data: Default::default(),
-            size: 0,
+            data: std::vec::Vec::with_capacity(s.min(capacity())),
         }
     }
 
-    pub fn with_capacity(_s: usize) -> Vec<T>
+    pub fn from_array<const N: usize>(_env: &Env, items: [T; N]) -> Vec<T> {
+        items.into()
+    }
+
+    pub fn from_slice(_env: &Env, items: &[T]) -> Vec<T>
     where
-        T: Default + Copy,
+        T: Clone,
     {
-        Vec::new(&Env::default())
+        Vec::new_from_slice(items)
     }
 
     pub fn new_from_slice(slice: &[T]) -> Vec<T>
     where
-        T: Default + Clone,
+        T: Clone,
     {
-        let mut v = Vec::new(&Env::default());
-        for z in slice {
-            v.push(z.clone());
-        }
+        let mut v = Vec::default();
+        v.extend_from_slice(slice);
         v
     }
 
+    /// Creates a vec holding `slice` whatever the capacity, for values
+    /// whose size is bounded some other way, like `BytesN`.
+    pub fn from_slice_unbounded(slice: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        Vec {
+            data: slice.to_vec(),
+        }
+    }
+
     pub fn env(&self) -> Env {
         Env::default()
     }
 
-    pub fn push(&mut self, t: T) {
-        self.data[self.size] = t;
-        self.size += 1;
+    /// Returns the elements converted to `Val`s.
+    pub fn to_vals(&self) -> Vec<Val>
+    where
+        T: ToValEnum,
+    {
+        self.data.iter().map(ToValEnum::to_val).collect()
+    }
+
+    pub fn len(&self) -> u32 {
+        self.data.len() as u32
     }
 
-    pub fn push_back(&mut self, t: T) {
-        self.push(t);
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the item at position `i`, or `None` if out of bounds.
+    pub fn get(&self, i: u32) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.data.get(i as usize).cloned()
     }
 
-    pub fn push_front(&mut self, t: T) {
-        self.insert(0, t);
+    /// Returns the item at position `i`, or `None` if out of bounds.
+    ///
+    /// Items always convert, as they are stored with their type.
+    pub fn try_get(&self, i: u32) -> Result<Option<T>, crate::ConversionError>
+    where
+        T: Clone,
+    {
+        Ok(self.get(i))
     }
 
-    pub fn pop(&mut self) {
-        self.size -= 1;
+    /// Returns the item at position `i`.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out of bounds.
+    #[track_caller]
+    pub fn get_unchecked(&self, i: u32) -> T
+    where
+        T: Clone,
+    {
+        self.get(i).unwrap_or_else(|| index_out_of_bounds())
     }
 
-    pub fn insert(&mut self, pos: usize, t: T) {
-        if pos > self.size {
-            panic!("oob");
+    /// Sets the item at position `i` to `v`.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out of bounds.
+    #[track_caller]
+    pub fn set(&mut self, i: u32, v: T) {
+        match self.data.get_mut(i as usize) {
+            Some(item) => *item = v,
+            None => index_out_of_bounds(),
         }
+    }
 
-        let mut v = std::mem::replace(&mut self.data[pos], t);
-        for i in pos + 1..self.size + 1 {
-            v = std::mem::replace(&mut self.data[i], v);
+    /// Removes the item at position `i`, returning `None` if out of bounds.
+    pub fn remove(&mut self, i: u32) -> Option<()> {
+        if i < self.len() {
+            self.data.remove(i as usize);
+            Some(())
+        } else {
+            None
         }
-        self.size += 1;
     }
 
-    pub fn len(&self) -> u32 {
-        self.size as u32
+    /// Removes the item at position `i`.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out of bounds.
+    #[track_caller]
+    pub fn remove_unchecked(&mut self, i: u32) {
+        if self.remove(i).is_none() {
+            index_out_of_bounds();
+        }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.size == 0
+    /// Adds the item to the front, shifting other items back.
+    pub fn push_front(&mut self, x: T) {
+        self.insert(0, x);
+    }
+
+    /// Removes and returns the first item, or `None` if empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.data.remove(0))
+        }
+    }
+
+    /// Removes and returns the first item.
+    ///
+    /// ### Panics
+    ///
+    /// If the vec is empty.
+    #[track_caller]
+    pub fn pop_front_unchecked(&mut self) -> T {
+        self.pop_front().unwrap_or_else(|| index_out_of_bounds())
     }
 
-    pub fn iter(&self) -> VecIterator<T> {
-        VecIterator { vec: self, idx: 0 }
+    /// Adds the item to the back.
+    pub fn push_back(&mut self, x: T) {
+        self.reserve_one();
+        self.data.push(x);
     }
 
-    pub fn get(&self, idx: u32) -> Option<T>
+    pub fn push(&mut self, x: T) {
+        self.push_back(x);
+    }
+
+    /// Removes and returns the last item, or `None` if empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    /// Removes and returns the last item.
+    ///
+    /// ### Panics
+    ///
+    /// If the vec is empty.
+    #[track_caller]
+    pub fn pop_back_unchecked(&mut self) -> T {
+        self.pop_back().unwrap_or_else(|| index_out_of_bounds())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    /// Returns the first item, or `None` if empty.
+    pub fn first(&self) -> Option<T>
     where
         T: Clone,
     {
-        let idx = idx as usize;
-        if idx >= self.size {
-            return None;
-        }
+        self.data.first().cloned()
+    }
 
-        Some(self.data[idx].clone())
+    /// Returns the first item.
+    ///
+    /// ### Panics
+    ///
+    /// If the vec is empty.
+    #[track_caller]
+    pub fn first_unchecked(&self) -> T
+    where
+        T: Clone,
+    {
+        self.first().unwrap_or_else(|| index_out_of_bounds())
     }
 
-    pub fn get_unchecked(&self, idx: u32) -> T
+    /// Returns the last item, or `None` if empty.
+    pub fn last(&self) -> Option<T>
     where
         T: Clone,
     {
-        self.get(idx).expect("Call get_unchecked failed")
+        self.data.last().cloned()
     }
 
-    pub fn contains(&self, t: &T) -> bool
+    /// Returns the last item.
+    ///
+    /// ### Panics
+    ///
+    /// If the vec is empty.
+    #[track_caller]
+    pub fn last_unchecked(&self) -> T
     where
-        T: PartialEq,
+        T: Clone,
     {
-        for i in 0..self.size {
-            if &self.data[i] == t {
-                return true;
-            }
-        }
+        self.last().unwrap_or_else(|| index_out_of_bounds())
+    }
 
-        false
+    /// Inserts an item at position `i`, shifting the items after it back.
+    ///
+    /// ### Panics
+    ///
+    /// If `i` is greater than the length of the vec.
+    #[track_caller]
+    pub fn insert(&mut self, i: u32, x: T) {
+        if i > self.len() {
+            index_out_of_bounds();
+        }
+        self.reserve_one();
+        self.data.insert(i as usize, x);
     }
 
-    pub fn sort(&mut self) {
-        // nothing
+    /// Appends the items of `other` to the back.
+    pub fn append(&mut self, other: &Vec<T>)
+    where
+        T: Clone,
+    {
+        self.extend_from_slice(other);
     }
 
-    pub fn dedup(&mut self) {
-        // todo
+    /// Adds the items of `iter` to the back.
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = T>) {
+        for x in iter {
+            self.push_back(x);
+        }
     }
 
-    pub fn remove(&mut self, idx: usize)
+    pub fn extend_from_slice(&mut self, slice: &[T])
     where
-        T: Copy,
+        T: Clone,
     {
-        if idx >= self.size {
-            panic!("oob");
+        for x in slice {
+            self.push_back(x.clone());
         }
+    }
 
-        for i in idx..self.size - 1 {
-            self.data[i] = self.data[i + 1];
+    /// Returns the items in range `r`.
+    ///
+    /// ### Panics
+    ///
+    /// If the range is out of bounds or its start is after its end.
+    #[track_caller]
+    pub fn slice(&self, r: impl RangeBounds<u32>) -> Self
+    where
+        T: Clone,
+    {
+        let start = match r.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .unwrap_or_else(|| index_out_of_bounds()),
+            Bound::Unbounded => 0,
+        };
+        let end = match r.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).unwrap_or_else(|| index_out_of_bounds()),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        if start > end || end > self.len() {
+            index_out_of_bounds();
         }
-        self.size -= 1;
+        Vec::new_from_slice(&self.data[start as usize..end as usize])
     }
 
-    pub fn binary_search(&self, t: &T) -> std::result::Result<usize, usize>
+    /// Returns whether the vec contains `item`.
+    pub fn contains(&self, item: impl Borrow<T>) -> bool
     where
         T: PartialEq,
     {
-        for i in 0..self.size {
-            if &self.data[i] == t {
-                return Ok(i);
-            }
-        }
+        self.data.contains(item.borrow())
+    }
+
+    /// Returns the position of the first occurrence of `item`.
+    pub fn first_index_of(&self, item: impl Borrow<T>) -> Option<u32>
+    where
+        T: PartialEq,
+    {
+        let item = item.borrow();
+        self.data.iter().position(|x| x == item).map(|i| i as u32)
+    }
+
+    /// Returns the position of the last occurrence of `item`.
+    pub fn last_index_of(&self, item: impl Borrow<T>) -> Option<u32>
+    where
+        T: PartialEq,
+    {
+        let item = item.borrow();
+        self.data.iter().rposition(|x| x == item).map(|i| i as u32)
+    }
 
-        Err(self.size)
+    /// Searches a sorted vec for `item`, returning `Ok` with its position if
+    /// found, or `Err` with the position it could be inserted at.
+    pub fn binary_search(&self, item: impl Borrow<T>) -> Result<u32, u32>
+    where
+        T: Ord,
+    {
+        self.data
+            .binary_search(item.borrow())
+            .map(|i| i as u32)
+            .map_err(|i| i as u32)
     }
 
-    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
+    /// Like [`Vec::binary_search`], for a vec sorted by the key `f`
+    /// extracts.
+    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, f: F) -> Result<u32, u32>
     where
         F: FnMut(&'a T) -> B,
         B: Ord,
     {
-        for i in 0..self.size {
-            if f(&self.data[i]) == *b {
-                return Ok(i);
-            }
-        }
-        Err(self.size)
+        self.data
+            .binary_search_by_key(b, f)
+            .map(|i| i as u32)
+            .map_err(|i| i as u32)
+    }
+
+    /// Sorts the items in ascending order.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.data.sort();
+    }
+
+    /// Removes consecutive repeated items, leaving a sorted vec without
+    /// duplicates.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.data.dedup();
     }
 
     pub fn as_slice(&self) -> &[T] {
-        &self.data[..self.size]
+        &self.data
     }
 
-    pub fn extend_from_slice(&mut self, slice: &[T])
+    /// Returns an iterator over clones of the items.
+    pub fn iter(&self) -> VecIterator<'_, T>
     where
-        T: Copy,
+        T: Clone,
     {
-        for z in slice {
-            self.push(*z);
+        VecIterator {
+            vec: self,
+            idx: 0,
+            end: self.data.len(),
         }
     }
 
-    /// Removes and returns the first item.
-    ///
-    /// ### Panics
-    ///
-    /// If the vec is empty.
-    pub fn pop_front_unchecked(&mut self) -> T
+    /// Returns an iterator over clones of the items, last first.
+    pub fn iter_rev(&self) -> Rev<VecIterator<'_, T>>
     where
-        T: Copy,
+        T: Clone,
     {
-        if self.size == 0 {
-            panic!("pop_front_unchecked called on empty Vec");
+        self.iter().rev()
+    }
+
+    // Fails, distinctly from contract panics, if one more element doesn't
+    // fit in the configured capacity.
+    fn reserve_one(&self) {
+        let capacity = capacity();
+        if self.data.len() >= capacity {
+            panic!("Vec capacity exceeded: at most {} elements", capacity);
         }
-        let res = self.data[0];
-        self.remove(0);
-        res
+    }
+}
+
+impl<T: Clone> Vec<Vec<T>> {
+    /// Returns the items of every vec, in order.
+    pub fn concat(&self) -> Vec<T> {
+        let mut v = Vec::default();
+        for items in self.data.iter() {
+            v.append(items);
+        }
+        v
     }
 }
 
@@ -221,33 +467,49 @@ impl<T> ops::Deref for Vec<T> {
 
     #[inline]
     fn deref(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.size) }
+        &self.data
     }
 }
 
 impl<T> ops::DerefMut for Vec<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut [T] {
-        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr(), self.size) }
+        &mut self.data
     }
 }
 
-impl<'a, T> Iterator for VecIterator<'a, T> {
-    type Item = &'a T;
+impl<T: Clone> Iterator for VecIterator<'_, T> {
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx >= self.vec.size {
+        if self.idx >= self.end {
             return None;
         }
-
-        let res = &self.vec.data[self.idx];
+        let res = self.vec.data[self.idx].clone();
         self.idx += 1;
         Some(res)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for VecIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.vec.data[self.end].clone())
+    }
 }
 
-impl<'a, T> IntoIterator for &'a Vec<T> {
-    type Item = &'a T;
+impl<T: Clone> ExactSizeIterator for VecIterator<'_, T> {}
+
+impl<'a, T: Clone> IntoIterator for &'a Vec<T> {
+    type Item = T;
     type IntoIter = VecIterator<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -255,32 +517,28 @@ impl<'a, T> IntoIterator for &'a Vec<T> {
     }
 }
 
-impl<T: Clone> Iterator for VecIntoIterator<T> {
+impl<T> Iterator for VecIntoIterator<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx >= self.vec.size {
-            return None;
-        }
-
-        let res = self.vec.data[self.idx].clone();
-        self.idx += 1;
-        Some(res)
+        self.iter.next()
     }
 }
 
-impl<T: Clone> IntoIterator for Vec<T> {
+impl<T> IntoIterator for Vec<T> {
     type Item = T;
     type IntoIter = VecIntoIterator<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        VecIntoIterator { vec: self, idx: 0 }
+        VecIntoIterator {
+            iter: self.data.into_iter(),
+        }
     }
 }
 
-impl<T: Default> FromIterator<T> for Vec<T> {
+impl<T> FromIterator<T> for Vec<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut v = Vec::new(&Env::default());
+        let mut v = Vec::default();
         for x in iter {
             v.push(x);
         }
@@ -288,9 +546,9 @@ impl<T: Default> FromIterator<T> for Vec<T> {
     }
 }
 
-impl<T: Default> FromIterator<std::vec::Vec<T>> for Vec<Vec<T>> {
+impl<T> FromIterator<std::vec::Vec<T>> for Vec<Vec<T>> {
     fn from_iter<I: IntoIterator<Item = std::vec::Vec<T>>>(iter: I) -> Self {
-        let mut v = Vec::new(&Env::default());
+        let mut v = Vec::default();
         for x in iter {
             v.push(x.into());
         }
@@ -298,56 +556,46 @@ impl<T: Default> FromIterator<std::vec::Vec<T>> for Vec<Vec<T>> {
     }
 }
 
-impl<T: Default, const N: usize> From<[T; N]> for Vec<T> {
+impl<T, const N: usize> From<[T; N]> for Vec<T> {
     fn from(arr: [T; N]) -> Vec<T> {
-        let mut vec = Vec::new(&Env::default());
-        for element in arr {
-            vec.push(element);
-        }
-        vec
+        arr.into_iter().collect()
     }
 }
 
-impl<T: Default> From<std::vec::Vec<T>> for Vec<T> {
+impl<T> From<std::vec::Vec<T>> for Vec<T> {
     fn from(value: std::vec::Vec<T>) -> Self {
-        let mut res = Vec::new(&Env::default());
-        for v in value.into_iter() {
-            res.push(v);
-        }
-        res
+        value.into_iter().collect()
     }
 }
 
-impl<T: Default + FromValEnum> FromValEnum for Vec<T> {
+impl<T: FromValEnum> FromValEnum for Vec<T> {
     fn from_val(val: Val) -> Option<Self> {
         if let Val::VecVal(u) = val {
-            if u.len() as usize > VEC_SIZE {
-                None
-            } else {
-                let mut v = Vec::new(&Env::default());
-                for i in u.iter() {
-                    let val: Val = <Rc<Val> as Borrow<Val>>::borrow(i).clone();
-                    v.push(T::from_val(val).unwrap());
-                }
-                Some(v)
+            let mut v = Vec::default();
+            for i in u {
+                let val: Val = <Rc<Val> as Borrow<Val>>::borrow(&i).clone();
+                v.push(T::from_val(val).unwrap());
             }
+            Some(v)
         } else {
             None
         }
     }
 }
 
-impl<T: Default + ToValEnum> ToValEnum for Vec<T> {
+impl<T: ToValEnum> ToValEnum for Vec<T> {
     fn to_val(&self) -> Val {
-        Val::VecVal(self.into_iter().map(|v| Rc::new(v.to_val())).collect())
+        Val::VecVal(self.data.iter().map(|v| Rc::new(v.to_val())).collect())
     }
 }
 
 #[cfg(kani)]
-impl<T: kani::Arbitrary + Default> kani::Arbitrary for Vec<T> {
+impl<T: kani::Arbitrary> kani::Arbitrary for Vec<T> {
     fn any() -> Self {
-        let mut v = Vec::new(&Env::default());
-        for _ in 0..kani::any::<u8>() % (VEC_SIZE as u8) {
+        let len: usize = kani::any();
+        kani::assume(len <= capacity());
+        let mut v = Vec::default();
+        for _ in 0..len {
             v.push(kani::any());
         }
         v
@@ -378,6 +626,109 @@ mod test {
         assert_eq!(vec.pop_front_unchecked(), 3);
     }
 
+    #[test]
+    fn test_vec_access() {
+        let env = Env::default();
+        let mut vec = Vec::from_array(&env, [1, 2, 3]);
+        assert_eq!(vec.first(), Some(1));
+        assert_eq!(vec.last(), Some(3));
+        assert_eq!(vec.try_get(1), Ok(Some(2)));
+        assert_eq!(vec.try_get(3), Ok(None));
+        vec.set(1, 5);
+        assert_eq!(vec.get_unchecked(1), 5);
+        assert_eq!(vec.remove(3), None);
+        assert_eq!(vec.remove(0), Some(()));
+        assert_eq!(vec.pop_back(), Some(3));
+        assert_eq!(vec.pop_front(), Some(5));
+        assert_eq!(vec.pop_back(), None);
+        assert_eq!(vec.first(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_vec_set_out_of_bounds() {
+        let mut vec = Vec::from_array(&Env::default(), [1, 2, 3]);
+        vec.set(3, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_vec_insert_out_of_bounds() {
+        let mut vec = Vec::from_array(&Env::default(), [1, 2, 3]);
+        vec.insert(4, 4);
+    }
+
+    #[test]
+    fn test_vec_slice() {
+        let vec = Vec::from_array(&Env::default(), [1, 2, 3, 4]);
+        assert_eq!(vec.slice(1..3), Vec::from([2, 3]));
+        assert_eq!(vec.slice(..=1), Vec::from([1, 2]));
+        assert_eq!(vec.slice(4..), Vec::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_vec_slice_out_of_bounds() {
+        let vec = Vec::from_array(&Env::default(), [1, 2, 3, 4]);
+        vec.slice(2..5);
+    }
+
+    #[test]
+    fn test_vec_search() {
+        let vec = Vec::from_array(&Env::default(), [1, 2, 2, 4]);
+        assert!(vec.contains(2));
+        assert_eq!(vec.first_index_of(2), Some(1));
+        assert_eq!(vec.last_index_of(2), Some(2));
+        assert_eq!(vec.first_index_of(3), None);
+        assert_eq!(vec.binary_search(4), Ok(3));
+        assert_eq!(vec.binary_search(3), Err(3));
+        assert_eq!(vec.binary_search(0), Err(0));
+    }
+
+    #[test]
+    fn test_vec_append_concat() {
+        let mut vec = Vec::from([1, 2]);
+        vec.append(&Vec::from([3]));
+        assert_eq!(vec, Vec::from([1, 2, 3]));
+        let vecs = Vec::from([vec, Vec::from([4])]);
+        assert_eq!(vecs.concat(), Vec::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_vec_iter() {
+        let vec = Vec::from([1u32, 2, 3]);
+        assert_eq!(vec.iter().collect::<std::vec::Vec<_>>(), [1, 2, 3]);
+        assert_eq!(vec.iter_rev().collect::<std::vec::Vec<_>>(), [3, 2, 1]);
+        assert_eq!(
+            vec.to_vals(),
+            Vec::from([1u32.to_val(), 2u32.to_val(), 3u32.to_val()])
+        );
+    }
+
+    #[test]
+    fn test_vec_holds_hash() {
+        let vec = Vec::new_from_slice(&[7u8; 32]);
+        assert_eq!(vec.len(), 32);
+        assert_eq!(vec[31], 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec capacity exceeded")]
+    fn test_vec_capacity_exceeded() {
+        let mut vec = Vec::new(&Env::default());
+        for i in 0..=capacity() {
+            vec.push(i);
+        }
+    }
+
+    #[test]
+    fn test_vec_set_capacity() {
+        set_capacity(64);
+        let vec: Vec<u8> = (0..64).collect();
+        set_capacity(DEFAULT_CAPACITY);
+        assert_eq!(vec.len(), 64);
+    }
+
     #[test]
     fn test_vec_from_iter() {
         let v = [1, 2, 3, 4, 5];
@@ -385,4 +736,20 @@ mod test {
         assert_eq!(vec.len(), 5);
         assert_eq!(vec[0], 1);
     }
+
+    #[test]
+    fn test_vec_sort_dedup_search() {
+        let mut vec = Vec::from([3u32, 1, 3, 2, 1]);
+        vec.sort();
+        vec.dedup();
+        assert_eq!(vec, Vec::from([1, 2, 3]));
+        assert_eq!(vec.binary_search(2), Ok(1));
+        assert_eq!(vec.binary_search_by_key(&8, |x| x * 2), Err(3));
+
+        let mut sum = 0;
+        for x in &vec {
+            sum += x;
+        }
+        assert_eq!(sum, 6);
+    }
 }
@@ -13,7 +13,9 @@ pub fn generate_fns(_name: &str, specs: &[&ScSpecFunctionV0]) -> TokenStream {
         .iter()
         .map(|s| {
             let fn_ident = format_ident!("{}", s.name.to_string());
+            let fn_name = s.name.to_string();
             let mut trait_fn_inputs = Vec::new();
+            let mut arg_vals = Vec::new();
 
             let fn_inputs: Vec<TokenStream> = s
                 .inputs
@@ -22,6 +24,7 @@ pub fn generate_fns(_name: &str, specs: &[&ScSpecFunctionV0]) -> TokenStream {
                     let name = format_ident!("{}", input.name.to_string());
                     let type_ident = generate_type_ident(&input.type_);
                     trait_fn_inputs.push(quote! { #name: #type_ident });
+                    arg_vals.push(quote! { soroban_sdk::ToValEnum::to_val(#name) });
                     quote! { #name: &#type_ident }
                 })
                 .collect();
@@ -32,10 +35,17 @@ pub fn generate_fns(_name: &str, specs: &[&ScSpecFunctionV0]) -> TokenStream {
                 .map(|t| generate_type_ident(&t))
                 .map(|t| quote! { -> #t });
 
+            // The imported contract's code isn't available, so it is treated
+            // as untrusted.
             let fn_implementation = quote! {
                 #[cfg(kani)]
                 {
-                    kani::any()
+                    let args = [#(#arg_vals),*];
+                    self.env.call_untrusted(
+                        &self.address,
+                        &soroban_sdk::Symbol::from(#fn_name),
+                        &args,
+                    )
                 }
                 #[cfg(not(kani))]
                 {
@@ -7,13 +7,32 @@ use syn::{
     FieldsUnnamed, FnArg, ItemFn, ItemTrait, Pat, PatIdent,
 };
 
-const KANI_UNWIND: usize = 20;
+// Enough to iterate over a `Vec` filled up to its default capacity.
+const KANI_UNWIND: usize = 34;
+
+#[derive(Debug, Default, FromMeta)]
+struct ContractImplArgs {
+    /// The `ContractFunctions` slot of the block, for blocks whose default
+    /// slot is taken (see `impl_block_slot`).
+    slot: Option<usize>,
+}
 
 #[proc_macro_attribute]
 pub fn contractimpl(
-    _attr: proc_macro::TokenStream,
+    attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let args = match NestedMeta::parse_meta_list(attr.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return proc_macro::TokenStream::from(darling::Error::from(e).write_errors());
+        }
+    };
+    let args = match ContractImplArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+
     let input = parse_macro_input!(item as syn::ItemImpl);
     let struct_name = input.self_ty.as_ref();
 
@@ -30,58 +49,359 @@ pub fn contractimpl(
 
     let client = syn::Ident::new(&format!("{}Client", name), name.span());
 
-    let methods = input.items.clone().into_iter().filter_map(|item| {
-        if let syn::ImplItem::Fn(method) = item {
-            let output = &method.sig.output;
-            let method_name = &method.sig.ident;
-            // let inputs = &method.sig.inputs;
-
-            let mut inputs = Vec::new();
-            inputs.push(syn::parse_quote! { &self });
-            for arg in method.sig.inputs.iter().skip(1) {
-                let transformed_arg = if let FnArg::Typed(pat_type) = arg {
-                    let syn::PatType { pat, ty, attrs, .. } = pat_type;
-                    let new_ty = quote! { &#ty };
-                    syn::parse_quote! { #(#attrs)* #pat: #new_ty }
-                } else {
-                    arg.clone()
-                };
+    let slot = match impl_block_slot(&input, args.slot) {
+        Ok(slot) => slot,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-                inputs.push(transformed_arg);
+    let functions: Vec<&syn::ImplItemFn> = input
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::ImplItem::Fn(method)
+                if method.sig.receiver().is_none()
+                    && method.sig.generics.params.is_empty()
+                    && !takes_references(&method.sig) =>
+            {
+                Some(method)
             }
+            _ => None,
+        })
+        .collect();
 
-            let ret = match output {
-                syn::ReturnType::Default => quote! {
-                    pub fn #method_name(#(#inputs),*) #output {}
-                },
-                syn::ReturnType::Type(_, _) => {
-                    quote! {
-                            pub fn #method_name(#(#inputs),*) #output {
-                                kani::any()
-                            }
+    let self_ty = &input.self_ty;
+    let call_path = |method_name: &Ident| match &input.trait_ {
+        Some((_, trait_path, _)) => quote! { <#self_ty as #trait_path>::#method_name },
+        None => quote! { <#self_ty>::#method_name },
+    };
 
-                    }
+    let methods = functions.iter().map(|method| {
+        let output = &method.sig.output;
+        let method_name = &method.sig.ident;
+        let fn_name = method_name.to_string();
+        let call_path = call_path(method_name);
+
+        let mut inputs = Vec::new();
+        let mut arg_vals = Vec::new();
+        let mut call_args = Vec::new();
+        for (i, arg) in method.sig.inputs.iter().enumerate() {
+            let FnArg::Typed(pat_type) = arg else {
+                continue;
+            };
+            let ty = &pat_type.ty;
+            if is_env_type(ty) {
+                call_args.push(quote! { self.env.clone() });
+                continue;
+            }
+            let arg_name = match pat_type.pat.as_ref() {
+                Pat::Ident(pat_ident) => pat_ident.ident.clone(),
+                _ => format_ident!("arg_{}", i),
+            };
+            inputs.push(quote! { #arg_name: &#ty });
+            arg_vals.push(quote! { soroban_sdk::ToValEnum::to_val(#arg_name) });
+            call_args.push(quote! { #arg_name.clone() });
+        }
+
+        quote! {
+            pub fn #method_name(&self, #(#inputs),*) #output {
+                let args = [#(#arg_vals),*];
+                // Contracts that were never registered are untrusted.
+                #[cfg(any(kani, feature = "kani"))]
+                if !self.env.has_contract(&self.address) {
+                    return self.env.call_untrusted(
+                        &self.address,
+                        &soroban_sdk::Symbol::from(#fn_name),
+                        &args,
+                    );
+                }
+                self.env.with_invocation(
+                    soroban_sdk::dispatch::Invocation {
+                        contract: self.address,
+                        fn_name: soroban_sdk::Symbol::from(#fn_name),
+                        args: soroban_sdk::Vec::new_from_slice(&args),
+                    },
+                    || #call_path(#(#call_args),*),
+                )
+            }
+        }
+    });
+
+    let dispatch_arms = functions.iter().map(|method| {
+        let method_name = &method.sig.ident;
+        let fn_name = method_name.to_string();
+        let call_path = call_path(method_name);
+
+        let mut arg_count = 0usize;
+        let call_args: Vec<TokenStream> = method
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_type) if is_env_type(&pat_type.ty) => Some(quote! { env.clone() }),
+                FnArg::Typed(pat_type) => {
+                    let ty = &pat_type.ty;
+                    let i = arg_count;
+                    arg_count += 1;
+                    Some(quote! { (&&soroban_sdk::dispatch::Conv::<#ty>::new()).decode(&args[#i]) })
+                }
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        let result = match &method.sig.output {
+            syn::ReturnType::Default => quote! {
+                #call_path(#(#call_args),*);
+                soroban_sdk::Val::Void
+            },
+            syn::ReturnType::Type(_, ty) => quote! {
+                let result = #call_path(#(#call_args),*);
+                (&&soroban_sdk::dispatch::Conv::<#ty>::new()).encode(&result)
+            },
+        };
+
+        quote! {
+            if *func == soroban_sdk::Symbol::from(#fn_name) {
+                if args.len() != #arg_count {
+                    panic!("{} expects {} arguments, got {}", #fn_name, #arg_count, args.len());
                 }
+                return Some({ #result });
+            }
+        }
+    });
+
+    let arbitrary_arms = functions.iter().map(|method| {
+        let method_name = &method.sig.ident;
+        let fn_name = method_name.to_string();
+        let call_path = call_path(method_name);
+
+        let mut arg_decls = Vec::new();
+        let mut arg_vals = Vec::new();
+        let mut call_args = Vec::new();
+        for (i, arg) in method.sig.inputs.iter().enumerate() {
+            let FnArg::Typed(pat_type) = arg else {
+                continue;
             };
+            let ty = &pat_type.ty;
+            if is_env_type(ty) {
+                call_args.push(quote! { env.clone() });
+                continue;
+            }
+            let arg_name = format_ident!("arg_{}", i);
+            arg_decls.push(
+                quote! { let #arg_name: #ty = (&&soroban_sdk::dispatch::Conv::<#ty>::new()).arbitrary(); },
+            );
+            arg_vals.push(quote! { soroban_sdk::ToValEnum::to_val(&#arg_name) });
+            call_args.push(quote! { #arg_name });
+        }
 
-            Some(ret)
-        } else {
-            None
+        let result = match &method.sig.output {
+            syn::ReturnType::Default => quote! {
+                #call_path(#(#call_args),*);
+                soroban_sdk::Val::Void
+            },
+            syn::ReturnType::Type(_, ty) => quote! {
+                let result = #call_path(#(#call_args),*);
+                (&&soroban_sdk::dispatch::Conv::<#ty>::new()).encode(&result)
+            },
+        };
+
+        quote! {
+            if *func == soroban_sdk::Symbol::from(#fn_name) {
+                #( #arg_decls )*
+                let args = [#(#arg_vals),*];
+                let invocation = soroban_sdk::dispatch::Invocation {
+                    contract: *contract,
+                    fn_name: *func,
+                    args: soroban_sdk::Vec::new_from_slice(&args),
+                };
+                return Some(env.with_invocation(invocation, || { #result }));
+            }
         }
     });
 
+    let fn_names = functions.iter().map(|method| method.sig.ident.to_string());
+
+    let token_interface_harnesses = generate_token_interface_harnesses(&input, &name);
+    let slot_guard = slot_guard(&input, slot);
+
     quote! {
         #input
 
-        #[cfg(any(kani, feature = "kani"))]
+        #slot_guard
+
         impl<'a> #client<'a> {
             #( #methods )*
+        }
+
+        impl soroban_sdk::dispatch::ContractFunctions<#slot> for #self_ty {
+            #[allow(unused_imports, unused_variables)]
+            fn call(
+                env: &soroban_sdk::Env,
+                func: &soroban_sdk::Symbol,
+                args: &[soroban_sdk::Val],
+            ) -> Option<soroban_sdk::Val> {
+                use soroban_sdk::dispatch::{
+                    ConvFromVal as _, ConvFromValFallback as _, ConvToVal as _, ConvToValFallback as _,
+                };
+                #( #dispatch_arms )*
+                None
+            }
+
+            fn functions() -> &'static [&'static str] {
+                &[#(#fn_names),*]
+            }
 
+            #[cfg(any(kani, feature = "kani"))]
+            #[allow(unused_imports, unused_variables)]
+            fn call_arbitrary(
+                env: &soroban_sdk::Env,
+                contract: &soroban_sdk::Address,
+                func: &soroban_sdk::Symbol,
+            ) -> Option<soroban_sdk::Val> {
+                use soroban_sdk::dispatch::{
+                    ConvArbitrary as _, ConvArbitraryFallback as _, ConvToVal as _, ConvToValFallback as _,
+                };
+                #( #arbitrary_arms )*
+                None
+            }
         }
+
+        #token_interface_harnesses
     }
     .into()
 }
 
+/// Maximum number of `#[contractimpl]` blocks per contract, matching
+/// `soroban_sdk::dispatch::MAX_IMPL_BLOCKS`.
+const MAX_IMPL_BLOCKS: usize = 64;
+
+/// Number of slots, out of `MAX_IMPL_BLOCKS`, for inherent impls.
+const INHERENT_SLOTS: usize = 16;
+
+/// Returns the `ContractFunctions` slot of `input`.
+///
+/// The slot only depends on the block itself, so every expansion agrees on
+/// it: inherent impls hash the names of their functions into the first
+/// `INHERENT_SLOTS` slots, and trait impls hash the name of the trait into
+/// the others. Two blocks of a contract can still land in the same slot,
+/// which [`slot_guard`] reports; `#[contractimpl(slot = N)]` moves one of
+/// them.
+fn impl_block_slot(input: &syn::ItemImpl, slot: Option<usize>) -> Result<usize, Error> {
+    let slot = match slot {
+        Some(slot) => slot,
+        None => {
+            let (key, slots) = match &input.trait_ {
+                Some((_, path, _)) => (
+                    path.segments.last().to_token_stream().to_string(),
+                    INHERENT_SLOTS..MAX_IMPL_BLOCKS,
+                ),
+                None => {
+                    let names: Vec<String> = input
+                        .items
+                        .iter()
+                        .filter_map(|item| match item {
+                            syn::ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                            _ => None,
+                        })
+                        .collect();
+                    (names.join(","), 0..INHERENT_SLOTS)
+                }
+            };
+            // FNV-1a, which unlike the std `DefaultHasher` is stable across
+            // compiler versions.
+            let hash = key.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+            slots.start + (hash % slots.len() as u64) as usize
+        }
+    };
+    if slot >= MAX_IMPL_BLOCKS {
+        return Err(Error::new_spanned(
+            input,
+            format!("#[contractimpl] slots go from 0 to {}", MAX_IMPL_BLOCKS - 1),
+        ));
+    }
+    Ok(slot)
+}
+
+/// Declares that `input` uses `slot`, so that two blocks of a contract in
+/// the same slot fail with a duplicate definition naming the slot, pointing
+/// at both blocks.
+fn slot_guard(input: &syn::ItemImpl, slot: usize) -> TokenStream {
+    let self_ty = &input.self_ty;
+    let guard = format_ident!(
+        "CONTRACTIMPL_SLOT_{}_IS_TAKEN",
+        slot,
+        span = input.impl_token.span
+    );
+    quote! {
+        impl #self_ty {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            const #guard: () = ();
+        }
+    }
+}
+
+fn is_env_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.segments.last().is_some_and(|seg| seg.ident == "Env"))
+}
+
+/// Contract functions take their arguments by value, so functions with
+/// reference arguments, like `TokenInterface::verify_setup`, are helpers
+/// rather than contract functions.
+fn takes_references(sig: &syn::Signature) -> bool {
+    sig.inputs.iter().any(|arg| {
+        matches!(arg, FnArg::Typed(pat_type) if matches!(*pat_type.ty, syn::Type::Reference(_)))
+    })
+}
+
+/// Emits a Kani harness for each `verify_*` hook of `TokenInterface` when
+/// `input` implements it, so every token contract is checked against the
+/// reference token specification once registered in a fresh `Env`.
+fn generate_token_interface_harnesses(input: &syn::ItemImpl, name: &Ident) -> TokenStream {
+    let implements_token_interface = input.trait_.as_ref().is_some_and(|(_, path, _)| {
+        path.segments
+            .last()
+            .is_some_and(|seg| seg.ident == "TokenInterface")
+    });
+    if !implements_token_interface {
+        return quote! {};
+    }
+
+    let module_name = format_ident!("__{}_token_interface_verification", name);
+    let harnesses = [
+        "verify_allowance",
+        "verify_approve",
+        "verify_balance",
+        "verify_transfer",
+        "verify_transfer_from",
+        "verify_burn",
+        "verify_burn_from",
+    ]
+    .iter()
+    .map(|hook| {
+        let hook = format_ident!("{}", hook);
+        quote! {
+            #[soroban_sdk::kani::proof]
+            #[soroban_sdk::kani::unwind(#KANI_UNWIND)]
+            pub fn #hook() {
+                let env = soroban_sdk::Env::default();
+                let token = env.register_with_any_args(super::#name);
+                <super::#name as soroban_sdk::token::TokenInterface>::#hook(&env, &token);
+            }
+        }
+    });
+
+    quote! {
+        #[cfg(any(kani, feature = "kani"))]
+        #[allow(non_snake_case)]
+        mod #module_name {
+            #( #harnesses )*
+        }
+    }
+}
+
 #[proc_macro_attribute]
 pub fn contract(
     _metadata: proc_macro::TokenStream,
@@ -91,10 +411,11 @@ pub fn contract(
     let name = &item.ident;
 
     let client = format_ident!("{}Client", name, span = name.span());
+    let slots: Vec<usize> = (0..MAX_IMPL_BLOCKS).collect();
 
     quote! {
         use soroban_sdk::{
-            token::AdminClient as TokenAdminClient_, token::Client as TokenClient_, verify, EnvTrait
+            token::StellarAssetClient as TokenAdminClient_, token::TokenClient as TokenClient_, verify, EnvTrait
         };
         #[cfg(any(kani, feature = "kani"))]
         use soroban_sdk::kani;
@@ -117,8 +438,45 @@ pub fn contract(
             }
         }
 
+        impl soroban_sdk::dispatch::ContractFunctionSet for #name {
+            fn call(
+                &self,
+                env: &soroban_sdk::Env,
+                func: &soroban_sdk::Symbol,
+                args: &[soroban_sdk::Val],
+            ) -> Option<soroban_sdk::Val> {
+                use soroban_sdk::dispatch::{SlotCall as _, SlotCallFallback as _};
+                #(
+                    if let Some(result) = (&&soroban_sdk::dispatch::Slot::<#name, #slots>::new()).call(env, func, args) {
+                        return Some(result);
+                    }
+                )*
+                None
+            }
+
+            fn functions(&self) -> soroban_sdk::dispatch::FunctionNames {
+                use soroban_sdk::dispatch::{SlotCall as _, SlotCallFallback as _};
+                [#( (&&soroban_sdk::dispatch::Slot::<#name, #slots>::new()).functions() ),*].concat()
+            }
+
+            fn call_arbitrary(
+                &self,
+                env: &soroban_sdk::Env,
+                contract: &soroban_sdk::Address,
+                func: &soroban_sdk::Symbol,
+            ) -> Option<soroban_sdk::Val> {
+                use soroban_sdk::dispatch::{SlotCall as _, SlotCallFallback as _};
+                #(
+                    if let Some(result) = (&&soroban_sdk::dispatch::Slot::<#name, #slots>::new()).call_arbitrary(env, contract, func) {
+                        return Some(result);
+                    }
+                )*
+                None
+            }
+        }
+
         impl #name {
-            fn create_token_contract<'a>(e: &soroban_sdk::Env, admin: &soroban_sdk::Address) -> (TokenClient_, TokenAdminClient_) {
+            fn create_token_contract<'a>(e: &soroban_sdk::Env, admin: &soroban_sdk::Address) -> (TokenClient_<'a>, TokenAdminClient_<'a>) {
                 let contract_address = e.register_stellar_asset_contract(admin.clone());
                 (
                     TokenClient_::new(e, &contract_address),
@@ -230,15 +588,25 @@ pub fn verify(
         }
     }
 
+    // Contracts are constructed when they are deployed, unless the
+    // constructor itself is being verified.
+    let register = if function_name == "__constructor" {
+        quote! { #env_name.register_contract(None, Self) }
+    } else {
+        quote! { #env_name.register_with_any_args(Self) }
+    };
+
+    // Run as the registered contract, so host functions like `require_auth`
+    // see its `Env`.
     let fn_call = if item_fn.sig.receiver().is_some() {
         quote! {
-            let result = #function_name();
+            let result = #env_name.as_contract(&contract_id, || #function_name());
         }
     } else {
         quote! {
-            let result = Self::#function_name(
+            let result = #env_name.as_contract(&contract_id, || Self::#function_name(
                 #(#arg_names),*
-            );
+            ));
         }
     };
 
@@ -252,7 +620,7 @@ pub fn verify(
         #visiblity fn #proof_name() {
             // Register the contract
             let #env_name = kani::any::<Env>();
-            let _ = #env_name.register_contract(None, 0);
+            let contract_id = #register;
             // First: Initialize the environment and declare the variables
             #(#arg_initializations)*
             #(#extracted_content)*
@@ -403,7 +771,7 @@ pub fn contracttype(
                 let deserialize_code = generate_deserialize_code(named);
 
                 // Generate the code for the FromValEnum and ToValEnum traits
-                let traits_code = generate_traits_for_structs(struct_name.clone());
+                let traits_code = generate_traits_for_structs(struct_name.clone(), &s.fields);
 
                 // Generate to_le_bytes and from_le_bytes
                 let to_from_bytes = generate_from_to_le_bytes(struct_name.clone());
@@ -432,7 +800,7 @@ pub fn contracttype(
                 let deserialize_code = generate_deserialize_code_unnamed(unnamed);
 
                 // Generate the code for the FromValEnum and ToValEnum traits
-                let traits_code = generate_traits_for_structs(struct_name.clone());
+                let traits_code = generate_traits_for_structs(struct_name.clone(), &s.fields);
 
                 // Generate to_le_bytes and from_le_bytes
                 let to_from_bytes = generate_from_to_le_bytes(struct_name.clone());
@@ -458,6 +826,31 @@ pub fn contracttype(
             .to_compile_error(),
         },
         Data::Enum(enum_data) => {
+            if let Some(variant) = enum_data
+                .variants
+                .iter()
+                .find(|v| matches!(v.fields, Fields::Named(_)))
+            {
+                return Error::new(
+                    variant.ident.span(),
+                    "enum variants with named fields are not supported as contract types",
+                )
+                .to_compile_error()
+                .into();
+            }
+            let discriminants = enum_data
+                .variants
+                .iter()
+                .filter(|v| v.discriminant.is_some())
+                .count();
+            if discriminants != 0 && discriminants != enum_data.variants.len() {
+                return Error::new(
+                    ident.span(),
+                    "either all or none of the variants of a contract type must have a discriminant",
+                )
+                .to_compile_error()
+                .into();
+            }
             let enum_name = &input.ident;
             let to_val_enum_impl = generate_to_val_enum(enum_data, enum_name);
             let from_val_enum_impl = generate_from_val_enum(enum_data, enum_name);
@@ -578,6 +971,15 @@ fn generate_deserialize_code(
                 };
             }
         };
+
+        // Strings are variable-length and encoded with their length first.
+        if matches!(field_ty, syn::Type::Path(path) if path.path.is_ident("String")) {
+            return quote! {
+                let #field_name = <#field_ty>::from_le_bytes(&buf[offset..]);
+                offset += 4 + #field_name.len() as usize;
+            };
+        }
+
         quote! {
             let mut #field_name_bytes = [0u8; #size_expr];
             #field_name_bytes.copy_from_slice(&buf[offset..offset + #size_expr]);
@@ -602,6 +1004,7 @@ fn generate_serialize_code_unnamed(
 ) -> proc_macro2::TokenStream {
     let field_serialization_statements = fields.iter().enumerate().map(|(i, field)| {
         let field_ty = &field.ty;
+        let i = syn::Index::from(i);
         quote! {
             let mut field_bytes = [0u8; core::mem::size_of::<#field_ty>()];
             field_bytes.copy_from_slice(&self.#i.to_le_bytes());
@@ -654,25 +1057,99 @@ fn generate_deserialize_code_unnamed(
     }
 }
 
-fn generate_traits_for_structs(name: Ident) -> proc_macro2::TokenStream {
+/// Named structs are maps keyed by field name, in key order, and tuple structs
+/// are vecs of their fields, as on the host.
+fn generate_traits_for_structs(name: Ident, fields: &Fields) -> proc_macro2::TokenStream {
+    let (to_val, from_val) = match fields {
+        Fields::Named(FieldsNamed { named, .. }) => {
+            let mut fields: Vec<&syn::Field> = named.iter().collect();
+            fields.sort_by_key(|field| field.ident.as_ref().unwrap().to_string());
+            let idents: Vec<&Ident> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+            let tys = fields.iter().map(|f| &f.ty);
+            let keys: Vec<TokenStream> = idents
+                .iter()
+                .map(|ident| symbol_const(&ident.to_string()))
+                .collect();
+            let bindings: Vec<Ident> = (0..fields.len())
+                .map(|i| format_ident!("field_{}", i))
+                .collect();
+            let len = fields.len();
+
+            let to_val = quote! {
+                soroban_sdk::Val::MapVal(alloc::vec![
+                    #((
+                        soroban_sdk::Val::SymbolVal(#keys),
+                        soroban_sdk::ToValEnum::to_val(&self.#idents),
+                    )),*
+                ])
+            };
+            let from_val = quote! {
+                let soroban_sdk::Val::MapVal(fields) = val else {
+                    return None;
+                };
+                let [#(#bindings),*] = <[(soroban_sdk::Val, soroban_sdk::Val); #len]>::try_from(fields).ok()?;
+                Some(Self {
+                    #(#idents: {
+                        let (key, value) = #bindings;
+                        if key != soroban_sdk::Val::SymbolVal(#keys) {
+                            return None;
+                        }
+                        <#tys as soroban_sdk::FromValEnum>::from_val(value)?
+                    }),*
+                })
+            };
+            (to_val, from_val)
+        }
+        _ => {
+            let indices: Vec<syn::Index> = (0..fields.len()).map(syn::Index::from).collect();
+            let tys = fields.iter().map(|f| &f.ty);
+            let len = fields.len() as u32;
+
+            let to_val = quote! {
+                soroban_sdk::Val::VecVal(soroban_sdk::Vec::from([
+                    #(alloc::rc::Rc::new(soroban_sdk::ToValEnum::to_val(&self.#indices))),*
+                ]))
+            };
+            let from_val = quote! {
+                let soroban_sdk::Val::VecVal(fields) = val else {
+                    return None;
+                };
+                if fields.len() != #len {
+                    return None;
+                }
+                Some(Self(
+                    #(<#tys as soroban_sdk::FromValEnum>::from_val((*fields[#indices]).clone())?),*
+                ))
+            };
+            (to_val, from_val)
+        }
+    };
+
     quote! {
         impl soroban_sdk::FromValEnum for #name {
             fn from_val(val: soroban_sdk::Val) -> Option<Self> {
-                match val {
-                    soroban_sdk::Val::Struct(bytes) => Some(Self::deserialize(&bytes)),
-                    _ => None,
-                }
+                #from_val
             }
         }
 
         impl soroban_sdk::ToValEnum for #name {
             fn to_val(&self) -> soroban_sdk::Val {
-                soroban_sdk::Val::Struct(self.serialize())
+                #to_val
             }
         }
     }
 }
 
+/// Evaluates to the symbol `name`, checked at compile time.
+fn symbol_const(name: &str) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            const SYMBOL: soroban_sdk::Symbol = soroban_sdk::Symbol::new_from_str(#name);
+            SYMBOL
+        }
+    }
+}
+
 fn generate_from_to_le_bytes(name: Ident) -> proc_macro2::TokenStream {
     quote! {
         fn to_le_bytes(&self) -> alloc::vec::Vec<u8> {
@@ -685,43 +1162,32 @@ fn generate_from_to_le_bytes(name: Ident) -> proc_macro2::TokenStream {
     }
 }
 
+/// Enums with explicit discriminants are `u32`s. Other enums are vecs holding
+/// the variant name followed by its fields, as on the host.
 fn generate_to_val_enum(enum_data: &DataEnum, enum_name: &Ident) -> proc_macro2::TokenStream {
-    let variants = &enum_data.variants;
     let mut arms = proc_macro2::TokenStream::new();
 
-    for variant in variants {
+    for variant in &enum_data.variants {
         let variant_ident = &variant.ident;
-        let variant_name = variant_ident.to_string();
 
-        let arm = match &variant.fields {
-            Fields::Unit => {
-                quote! {
-                    #enum_name::#variant_ident => soroban_sdk::Val::EnumVal(
-                        soroban_sdk::EnumType {
-                            variant: soroban_sdk::symbol_short!(#variant_name),
-                            value: alloc::vec::Vec::new().into(),
-                        }
-                    ),
-                }
-            }
-            Fields::Named(_) => {
-                quote! {
-                    Error::new(
-                        ident.span(),
-                        "named structs are not supported as contract types"
-                    )
-                    .to_compile_error()
-                }
+        let arm = if let Some((_, discriminant)) = &variant.discriminant {
+            quote! {
+                #enum_name::#variant_ident => soroban_sdk::Val::U32Val((#discriminant) as u32),
             }
-            Fields::Unnamed(_) => {
-                quote! {
-                    #enum_name::#variant_ident(data) => soroban_sdk::Val::EnumVal(
-                        soroban_sdk::EnumType {
-                            variant: soroban_sdk::symbol_short!(#variant_name),
-                            value: data.to_le_bytes().to_vec().into(),
-                        }
-                    ),
-                }
+        } else {
+            let name = symbol_const(&variant_ident.to_string());
+            let bindings: Vec<Ident> = (0..variant.fields.len())
+                .map(|i| format_ident!("field_{}", i))
+                .collect();
+            let pattern = match &variant.fields {
+                Fields::Unit => quote! { #enum_name::#variant_ident },
+                _ => quote! { #enum_name::#variant_ident(#(#bindings),*) },
+            };
+            quote! {
+                #pattern => soroban_sdk::Val::VecVal(soroban_sdk::Vec::from([
+                    alloc::rc::Rc::new(soroban_sdk::Val::SymbolVal(#name)),
+                    #(alloc::rc::Rc::new(soroban_sdk::ToValEnum::to_val(#bindings)),)*
+                ])),
             }
         };
 
@@ -740,55 +1206,63 @@ fn generate_to_val_enum(enum_data: &DataEnum, enum_name: &Ident) -> proc_macro2:
 }
 
 fn generate_from_val_enum(data: &DataEnum, enum_name: &Ident) -> proc_macro2::TokenStream {
-    let variants = &data.variants;
-    let mut arms = proc_macro2::TokenStream::new();
-
-    for variant in variants {
-        let variant_ident = &variant.ident;
-        let variant_name = variant_ident.to_string();
-
-        let arm = match &variant.fields {
-            Fields::Unit => {
-                quote! {
-                    #variant_name => Some(#enum_name::#variant_ident),
+    let is_integer_enum = data.variants.iter().any(|v| v.discriminant.is_some());
+
+    if is_integer_enum {
+        let arms = data.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let (_, discriminant) = variant.discriminant.as_ref().unwrap();
+            quote! {
+                soroban_sdk::Val::U32Val(value) if value == (#discriminant) as u32 => {
+                    Some(#enum_name::#variant_ident)
                 }
             }
-            Fields::Named(_) => {
-                quote! {
-                    Error::new(
-                        ident.span(),
-                        "named structs are not supported as contract types"
-                    )
-                    .to_compile_error()
+        });
+        return quote! {
+            impl soroban_sdk::FromValEnum for #enum_name {
+                fn from_val(val: soroban_sdk::Val) -> Option<Self> {
+                    match val {
+                        #(#arms)*
+                        _ => None,
+                    }
                 }
             }
-            Fields::Unnamed(unnamed_feilds) => {
-                let ty = &unnamed_feilds.unnamed.last().unwrap().ty;
+        };
+    }
+
+    let arms = data.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+        let len = variant.fields.len();
+        match &variant.fields {
+            Fields::Unit => quote! {
+                #variant_name if values.is_empty() => Some(#enum_name::#variant_ident),
+            },
+            _ => {
+                let indices = 0..len;
+                let tys = variant.fields.iter().map(|f| &f.ty);
                 quote! {
-                    #variant_name => Some(#enum_name::#variant_ident(
-                        <#ty>::from_le_bytes(
-                            enumval.value.try_into().unwrap()
-                        )
+                    #variant_name if values.len() == #len => Some(#enum_name::#variant_ident(
+                        #(<#tys as soroban_sdk::FromValEnum>::from_val((*values[#indices]).clone())?),*
                     )),
                 }
             }
-        };
-
-        arms.extend(arm);
-    }
-    arms.extend(quote! {
-        _ => None,
+        }
     });
 
     quote! {
         impl soroban_sdk::FromValEnum for #enum_name {
             fn from_val(val: soroban_sdk::Val) -> Option<Self> {
-                if let soroban_sdk::Val::EnumVal(enumval) = val {
-                    match enumval.variant.as_str() {
-                        #arms
-                    }
-                }  else {
-                    None
+                let soroban_sdk::Val::VecVal(items) = val else {
+                    return None;
+                };
+                let (variant, values) = items.split_first()?;
+                let soroban_sdk::Val::SymbolVal(variant) = &**variant else {
+                    return None;
+                };
+                match variant.as_str() {
+                    #(#arms)*
+                    _ => None,
                 }
             }
         }
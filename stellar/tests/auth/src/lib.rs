@@ -23,7 +23,7 @@ impl IncrementContract {
         init({
             let env = Env::default();
             let user = Address::new(&env);
-            let byte_data: BytesN<32> = BytesN::from_array(&[
+            let byte_data: BytesN<32> = BytesN::from_array(&env, &[
                 1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8,
             ]);
         }),
@@ -31,7 +31,7 @@ impl IncrementContract {
             true
         }),
         post_condition({
-            env.storage().persistent().get::<_, DataKey>(&DataKey::Counter(user)).unwrap_or(DataKey::SignerCnt) == DataKey::Data(BytesN::from_array(&[
+            env.storage().persistent().get::<_, DataKey>(&DataKey::Counter(user)).unwrap_or(DataKey::SignerCnt) == DataKey::Data(BytesN::from_array(&env, &[
                 1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8,
             ]))
     }))]
@@ -83,11 +83,15 @@ mod test {
     fn test_increment() {
         let env = Env::default();
         let user = Address::new(&env);
-        let bydata = BytesN::from_array(&[
-            1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4,
-            5, 6, 7, 8,
-        ]);
-        let hello = IncrementContract::increment(env.clone(), user, bydata);
+        let bydata = BytesN::from_array(
+            &env,
+            &[
+                1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3,
+                4, 5, 6, 7, 8,
+            ],
+        );
+        let contract_id = env.register_contract(None, IncrementContract);
+        let hello = IncrementContractClient::new(&env, &contract_id).increment(&user, &bydata);
 
         match hello {
             DataKey::Data(data) => {
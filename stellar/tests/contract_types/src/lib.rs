@@ -8,7 +8,7 @@ pub struct Counter {
     pub increments: u64,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 #[contracttype]
 pub struct User {
     pub address: Address,
@@ -50,10 +50,13 @@ mod test {
             address: Address::new(&env),
             name: Symbol::from("test"),
             age: 18,
-            data: BytesN::from_array(&[
-                1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3,
-                4, 5, 6, 7, 8,
-            ]),
+            data: BytesN::from_array(
+                &env,
+                &[
+                    1u8, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2,
+                    3, 4, 5, 6, 7, 8,
+                ],
+            ),
         };
         let serialized = user.serialize();
         let deserialized = User::deserialize(&serialized);
@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contracterror, contractimpl, log, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{contract, contracterror, contractimpl, log, symbol_short, Env, Symbol};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -21,7 +21,9 @@ impl IncrementContract {
     #[cfg_attr(any(kani, feature = "kani"), 
         verify,
         init({
-            env.storage().instance().set(&COUNTER, &kani::any::<u32>());
+            env.as_contract(&contract_id, || {
+                env.storage().instance().set(&COUNTER, &kani::any::<u32>());
+            });
         }),
         succeeds_if({
             env.storage().instance().get(&COUNTER).unwrap_or(0) < MAX
@@ -3,10 +3,7 @@
 #[contract]
 struct SimpleAccount;
 
-use soroban_sdk::{
-    auth::Context, contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol,
-    Vec,
-};
+use soroban_sdk::{auth::Context, contract, contractimpl, symbol_short, BytesN, Env, Symbol, Vec};
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
 
@@ -16,7 +13,9 @@ impl SimpleAccount {
     #[cfg_attr(any(kani, feature = "kani"), 
         verify,
         init({
-            env.storage().instance().set(&ADMIN, &kani::any::<BytesN<32>>());
+            env.as_contract(&contract_id, || {
+                env.storage().instance().set(&ADMIN, &kani::any::<BytesN<32>>());
+            });
         }),
         succeeds_if({
             !env.storage().instance().has(&ADMIN)
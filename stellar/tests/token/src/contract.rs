@@ -68,6 +68,18 @@ impl Token {
 
 #[contractimpl]
 impl token::TokenInterface for Token {
+    fn verify_setup(e: &Env, id: &Address, amount: i128) {
+        let admin = Address::new(e);
+        Token::initialize(
+            e.clone(),
+            admin,
+            7,
+            String::from("Token"),
+            String::from("TKN"),
+        );
+        Token::mint(e.clone(), *id, amount);
+    }
+
     fn allowance(e: Env, from: Address, spender: Address) -> i128 {
         e.storage()
             .instance()
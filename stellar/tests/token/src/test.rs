@@ -4,9 +4,11 @@ extern crate std;
 use crate::{
     admin::{read_administrator, write_administrator},
     balance::{read_balance, receive_balance},
+    contract::Token,
     storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD},
+    TokenClient,
 };
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{token, Address, Env, String};
 
 #[test]
 fn test() {
@@ -20,3 +22,52 @@ fn test() {
 
     let post_balance = read_balance(&e, to);
 }
+
+#[test]
+fn test_token_client_dispatches_to_contract() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::new(&e);
+    let from = Address::new(&e);
+    let to = Address::new(&e);
+
+    let address = e.register_contract(None, Token);
+    let contract = TokenClient::new(&e, &address);
+    contract.initialize(&admin, &7, &String::from("Token"), &String::from("TKN"));
+    contract.mint(&from, &100);
+
+    let client = token::TokenClient::new(&e, &address);
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.name(), String::from("Token"));
+    client.transfer(&from, &to, &30);
+    assert_eq!(client.balance(&from), 70);
+    assert_eq!(client.balance(&to), 30);
+
+    client.approve(&from, &to, &20, &100);
+    assert_eq!(client.allowance(&from, &to), 20);
+    client.transfer_from(&to, &from, &to, &5);
+    client.burn(&to, &10);
+    assert_eq!(contract.balance(&from), 65);
+    assert_eq!(contract.balance(&to), 25);
+}
+
+#[test]
+fn test_asset_client_dispatches_to_contract() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::new(&e);
+    let new_admin = Address::new(&e);
+    let to = Address::new(&e);
+
+    let address = e.register_contract(None, Token);
+    let contract = TokenClient::new(&e, &address);
+    contract.initialize(&admin, &7, &String::from("Token"), &String::from("TKN"));
+
+    let client = token::StellarAssetClient::new(&e, &address);
+    client.mint(&to, &100);
+    assert_eq!(contract.balance(&to), 100);
+    assert_eq!(client.balance(&to), 100);
+
+    client.set_admin(&new_admin);
+    assert_eq!(e.as_contract(&address, || read_administrator(&e)), new_admin);
+}